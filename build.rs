@@ -0,0 +1,94 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Generates the `__input_test!` macro used by `aoc_tests!`, which marks the input test of a part as
+ * ignored if its puzzle input in `src/inputs/` or its stored answer in `src/answers/` is missing,
+ * e.g. in CI. Tests that can't check anything are then reported as ignored instead of passing.
+ */
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Reads the default year from `src/lib.rs`, whose files live directly in `src/`.
+fn default_year() -> String {
+  fs::read_to_string("src/lib.rs")
+    .ok()
+    .and_then(|lib| {
+      lib.lines().find_map(|line| {
+        let year = line.strip_prefix("pub const DEFAULT_YEAR: u16 = ")?;
+        Some(year.trim_end_matches(';').to_string())
+      })
+    })
+    .expect("src/lib.rs should declare `pub const DEFAULT_YEAR: u16 = <year>;`")
+}
+
+/// The directory of each year, keyed by how `aoc_tests!` refers to the year.
+fn year_dirs(default_year: &str) -> Vec<(String, PathBuf)> {
+  let years = fs::read_dir("src")
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter_map(|entry| {
+          let name = entry.file_name().to_string_lossy().to_string();
+          let year = name.strip_prefix('y')?.parse::<u16>().ok()?;
+          Some((year.to_string(), entry.path()))
+        })
+        .collect::<Vec<_>>()
+    })
+    .unwrap_or_default();
+
+  let src = PathBuf::from("src");
+  [
+    vec![
+      ("default".to_string(), src.clone()),
+      (default_year.to_string(), src),
+    ],
+    years,
+  ]
+  .concat()
+}
+
+/// Empty files, like the inputs created by `cargo scaffold`, count as missing.
+fn has_contents(path: &Path) -> bool {
+  fs::read_to_string(path).is_ok_and(|contents| !contents.trim().is_empty())
+}
+
+fn main() {
+  // watches all of `src/` so that new inputs, answers and years are picked up.
+  println!("cargo:rerun-if-changed=src");
+
+  let mut arms = String::new();
+  for (year, dir) in year_dirs(&default_year()) {
+    for day in 1..=25 {
+      if !has_contents(&dir.join("inputs").join(format!("{:02}.txt", day))) {
+        continue;
+      }
+      for part in 1..=2 {
+        let answer = dir.join("answers").join(format!("{:02}-{}.txt", day, part));
+        if has_contents(&answer) {
+          arms.push_str(&format!(
+            "  ({}, {}, {}, $($test:tt)*) => {{ $($test)* }};\n",
+            year, day, part
+          ));
+        }
+      }
+    }
+  }
+
+  let generated = format!(
+    r#"/// Generated by `build.rs`: keeps the input tests of parts with a puzzle input and a stored answer.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __input_test {{
+{}  ($year:tt, $day:tt, $part:tt, $($test:tt)*) => {{
+    #[ignore = "no puzzle input or stored answer"]
+    $($test)*
+  }};
+}}
+"#,
+    arms
+  );
+
+  let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("input_tests.rs");
+  fs::write(out, generated).unwrap();
+}
@@ -0,0 +1,696 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Fetches puzzle inputs and descriptions from adventofcode.com with your session cookie.
+ */
+use crate::cache::{Cache, Kind};
+use crate::{year_dir, DEFAULT_YEAR};
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Overrides the address of the website, e.g. to test against a local server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Holds the session cookie. Takes precedence over the session file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Name of the file in the home directory that holds the session cookie, shared with aoc-cli.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Overrides the user agent of requests, e.g. to add a way to contact you.
+pub const USER_AGENT_VAR: &str = "AOC_USER_AGENT";
+
+/// `AOC_USER_AGENT`, or the name and version of this crate and its `repository` from `Cargo.toml`.
+fn user_agent() -> String {
+  env::var(USER_AGENT_VAR)
+    .ok()
+    .filter(|agent| !agent.trim().is_empty())
+    .unwrap_or_else(|| match env!("CARGO_PKG_REPOSITORY") {
+      "" => concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_string(),
+      repository => format!(
+        "{}/{} (+{})",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        repository
+      ),
+    })
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+  /// Neither `AOC_SESSION` nor the session file are set.
+  MissingSession,
+  /// The website did not accept the session cookie, usually because it expired.
+  Unauthorized,
+  /// The day does not exist or is not unlocked yet.
+  NotFound {
+    year: u16,
+    day: u8,
+  },
+  Status(u16),
+  /// The request did not get a response, e.g. without network.
+  Transport(String),
+  /// The puzzle page did not contain a description.
+  NoPuzzle,
+  /// Reading offline, but the puzzle was not downloaded before.
+  NotDownloaded {
+    path: PathBuf,
+    year: u16,
+    day: u8,
+  },
+  Io {
+    path: PathBuf,
+    source: io::Error,
+  },
+}
+
+impl Display for ClientError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ClientError::MissingSession => write!(
+        f,
+        "no session cookie found. Set the {} environment variable or paste your session cookie into \"~/{}\".",
+        SESSION_VAR, SESSION_FILE
+      ),
+      ClientError::Unauthorized => write!(
+        f,
+        "the session cookie was rejected, it might have expired. Copy a fresh one from your browser."
+      ),
+      ClientError::NotFound { year, day } => {
+        write!(f, "day {} of {} is not available (yet).", day, year)
+      }
+      ClientError::Status(status) => write!(f, "unexpected response status {}.", status),
+      ClientError::Transport(e) => write!(f, "request failed: {}", e),
+      ClientError::NoPuzzle => write!(f, "could not find the puzzle description in the page."),
+      ClientError::NotDownloaded { path, year, day } => {
+        write!(f, "could not find \"{}\". Run `cargo download {}", path.display(), day)?;
+        if *year != DEFAULT_YEAR {
+          write!(f, " --year {}", year)?;
+        }
+        write!(f, "` while online.")
+      }
+      ClientError::Io { path, source } => {
+        write!(f, "could not write \"{}\": {}", path.display(), source)
+      }
+    }
+  }
+}
+
+impl std::error::Error for ClientError {}
+
+pub struct Client {
+  base_url: String,
+  session: String,
+  agent: ureq::Agent,
+}
+
+impl Client {
+  pub fn new(base_url: &str, session: &str) -> Client {
+    Client {
+      base_url: base_url.trim_end_matches('/').to_string(),
+      session: session.to_string(),
+      agent: ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(30))
+        .user_agent(&user_agent())
+        .build(),
+    }
+  }
+
+  /// Reads the base url and session cookie from `AOC_BASE_URL`, `AOC_SESSION` and
+  /// `~/.adventofcode.session`.
+  pub fn from_env() -> Result<Client, ClientError> {
+    let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+    let session = env::var(SESSION_VAR)
+      .ok()
+      .or_else(|| {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+        fs::read_to_string(Path::new(&home).join(SESSION_FILE)).ok()
+      })
+      .map(|session| session.trim().to_string())
+      .filter(|session| !session.is_empty())
+      .ok_or(ClientError::MissingSession)?;
+
+    Ok(Client::new(&base_url, &session))
+  }
+
+  pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+    self.get(year, day, &format!("/{}/day/{}/input", year, day))
+  }
+
+  /// The description of the puzzle as markdown, including part two once it is unlocked.
+  pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+    let html = self.get(year, day, &format!("/{}/day/{}", year, day))?;
+    let markdown = articles(&html)
+      .iter()
+      .map(|article| html_to_markdown(article))
+      .collect::<Vec<_>>();
+
+    if markdown.is_empty() {
+      return Err(ClientError::NoPuzzle);
+    }
+    Ok(markdown.join("\n\n") + "\n")
+  }
+
+  /// Submits the answer of a part and reports what the website made of it.
+  pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome, ClientError> {
+    let response = self
+      .request("POST", &format!("/{}/day/{}/answer", year, day))
+      .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+    let html = text(year, day, response)?;
+
+    Ok(Outcome::from_html(&html))
+  }
+
+  fn get(&self, year: u16, day: u8, path: &str) -> Result<String, ClientError> {
+    text(year, day, self.request("GET", path).call())
+  }
+
+  fn request(&self, method: &str, path: &str) -> ureq::Request {
+    self
+      .agent
+      .request(method, &format!("{}{}", self.base_url, path))
+      .set("Cookie", &format!("session={}", self.session))
+  }
+}
+
+fn text(
+  year: u16,
+  day: u8,
+  response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+  response
+    .map_err(|e| match e {
+      ureq::Error::Status(400 | 401, _) => ClientError::Unauthorized,
+      ureq::Error::Status(404, _) => ClientError::NotFound { year, day },
+      ureq::Error::Status(status, _) => ClientError::Status(status),
+      ureq::Error::Transport(e) => ClientError::Transport(e.to_string()),
+    })?
+    .into_string()
+    .map_err(|e| ClientError::Transport(e.to_string()))
+}
+
+/// The verdict of the website on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+  Correct,
+  TooHigh,
+  TooLow,
+  /// Wrong, without a hint in which direction.
+  Incorrect,
+  /// Submitted too soon after the previous answer. Holds the time left to wait.
+  RateLimited(Duration),
+  /// The part was solved before.
+  AlreadySolved,
+  /// A response the template does not know, with its text.
+  Unknown(String),
+}
+
+impl Outcome {
+  /// Reads the verdict from the page returned after submitting an answer.
+  pub fn from_html(html: &str) -> Outcome {
+    let text = match articles(html).first() {
+      Some(article) => html_to_markdown(article),
+      None => html_to_markdown(html),
+    };
+
+    if text.contains("That's the right answer") {
+      Outcome::Correct
+    } else if text.contains("answer is too high") {
+      Outcome::TooHigh
+    } else if text.contains("answer is too low") {
+      Outcome::TooLow
+    } else if text.contains("That's not the right answer") {
+      Outcome::Incorrect
+    } else if text.contains("You gave an answer too recently") {
+      Outcome::RateLimited(parse_wait(&text).unwrap_or_default())
+    } else if text.contains("You don't seem to be solving the right level") {
+      Outcome::AlreadySolved
+    } else {
+      Outcome::Unknown(text)
+    }
+  }
+
+  /// Whether the outcome says anything about the answer, i.e. it should not be submitted again.
+  pub fn is_verdict(&self) -> bool {
+    matches!(
+      self,
+      Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+    )
+  }
+}
+
+/// Reads the time left from e.g. "You have 1m 5s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+  let start = text.find("You have ")? + "You have ".len();
+  let end = start + text[start..].find(" left to wait")?;
+
+  text[start..end]
+    .split_whitespace()
+    .map(|amount| match amount.split_at(amount.len() - 1) {
+      (minutes, "m") => minutes.parse::<u64>().ok().map(|m| m * 60),
+      (seconds, "s") => seconds.parse::<u64>().ok(),
+      _ => None,
+    })
+    .sum::<Option<u64>>()
+    .map(Duration::from_secs)
+}
+
+impl Display for Outcome {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Outcome::Correct => write!(f, "That's the right answer!"),
+      Outcome::TooHigh => write!(f, "That's not the right answer, it is too high."),
+      Outcome::TooLow => write!(f, "That's not the right answer, it is too low."),
+      Outcome::Incorrect => write!(f, "That's not the right answer."),
+      Outcome::RateLimited(wait) => write!(
+        f,
+        "You gave an answer too recently, wait {}s before trying again.",
+        wait.as_secs()
+      ),
+      Outcome::AlreadySolved => write!(f, "This part was already solved."),
+      Outcome::Unknown(text) => write!(f, "Unexpected response: {}", text),
+    }
+  }
+}
+
+/// A file of a day after `download`.
+pub struct Download {
+  pub path: PathBuf,
+  /// When the file was downloaded, if it was kept because it is unchanged since.
+  pub cached: Option<u64>,
+}
+
+pub struct Downloaded {
+  pub input: Download,
+  pub puzzle: Download,
+}
+
+/// Downloads the input and description of a day into `src/inputs/` and `src/puzzles/` of its year.
+/// Files that are unchanged since their last download are kept, unless `force` is set. Puzzles are
+/// downloaded again until they include part two.
+pub fn download(day: u8, year: Option<u16>, force: bool) -> Result<Downloaded, ClientError> {
+  let year = year.unwrap_or(DEFAULT_YEAR);
+  let mut cache = Cache::load(year);
+  let input_path = file_path(year, Kind::Input, day);
+  let puzzle_path = file_path(year, Kind::Puzzle, day);
+
+  let cached_input = cache
+    .unchanged(day, Kind::Input, &input_path)
+    .filter(|_| !force)
+    .map(|e| e.timestamp);
+  let cached_puzzle = cache
+    .unchanged(day, Kind::Puzzle, &puzzle_path)
+    .filter(|_| !force && fs::read_to_string(&puzzle_path).is_ok_and(|p| is_complete(&p)))
+    .map(|e| e.timestamp);
+
+  if cached_input.is_none() || cached_puzzle.is_none() {
+    let client = Client::from_env()?;
+    // fetches both before writing, so a failed request doesn't leave half a day behind.
+    let puzzle = match cached_puzzle {
+      Some(_) => None,
+      None => Some(client.puzzle(year, day)?),
+    };
+    let input = match cached_input {
+      Some(_) => None,
+      None => Some(client.input(year, day)?),
+    };
+
+    if let Some(puzzle) = puzzle {
+      store(&mut cache, day, Kind::Puzzle, &puzzle_path, &puzzle)?;
+    }
+    if let Some(input) = input {
+      store(&mut cache, day, Kind::Input, &input_path, &input)?;
+    }
+  }
+
+  Ok(Downloaded {
+    input: Download {
+      path: input_path,
+      cached: cached_input,
+    },
+    puzzle: Download {
+      path: puzzle_path,
+      cached: cached_puzzle,
+    },
+  })
+}
+
+/// The description of a day as markdown. Shows the downloaded puzzle unless `downloads.txt` knows
+/// it as an unchanged download of part one only, then downloads it again to get part two.
+/// Without a session, or with `offline`, the downloaded puzzle is shown in any case.
+pub fn read(day: u8, year: Option<u16>, offline: bool) -> Result<String, ClientError> {
+  let year = year.unwrap_or(DEFAULT_YEAR);
+  let path = file_path(year, Kind::Puzzle, day);
+  let local = fs::read_to_string(&path).ok();
+
+  let mut cache = Cache::load(year);
+  let local = match local {
+    Some(puzzle) if offline || is_complete(&puzzle) => return Ok(puzzle),
+    // puzzles that were not downloaded by the template, or were edited since, are shown as-is.
+    Some(puzzle) if cache.unchanged(day, Kind::Puzzle, &path).is_none() => return Ok(puzzle),
+    Some(puzzle) => Some(puzzle),
+    None if offline => return Err(ClientError::NotDownloaded { path, year, day }),
+    None => None,
+  };
+
+  let client = match (Client::from_env(), local) {
+    (Ok(client), _) => client,
+    (Err(ClientError::MissingSession), Some(puzzle)) => return Ok(puzzle),
+    (Err(e), _) => return Err(e),
+  };
+  let puzzle = client.puzzle(year, day)?;
+  store(&mut cache, day, Kind::Puzzle, &path, &puzzle)?;
+  Ok(puzzle)
+}
+
+fn file_path(year: u16, kind: Kind, day: u8) -> PathBuf {
+  match kind {
+    Kind::Input => year_dir(year)
+      .join("inputs")
+      .join(format!("{:02}.txt", day)),
+    Kind::Puzzle => year_dir(year)
+      .join("puzzles")
+      .join(format!("{:02}.md", day)),
+  }
+}
+
+/// Until part one is solved, the description only contains part one.
+fn is_complete(puzzle: &str) -> bool {
+  puzzle.contains("--- Part Two ---")
+}
+
+fn store(
+  cache: &mut Cache,
+  day: u8,
+  kind: Kind,
+  path: &Path,
+  contents: &str,
+) -> Result<(), ClientError> {
+  write(path, contents)?;
+  cache
+    .record(day, kind, contents)
+    .map_err(|source| ClientError::Io {
+      path: cache.path().to_path_buf(),
+      source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ClientError> {
+  let io_error = |source| ClientError::Io {
+    path: path.to_path_buf(),
+    source,
+  };
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir).map_err(io_error)?;
+  }
+  fs::write(path, contents).map_err(io_error)
+}
+
+/// The contents of all `<article>` elements of a page, i.e. the descriptions of both parts.
+fn articles(html: &str) -> Vec<&str> {
+  let mut articles = vec![];
+  let mut rest = html;
+  while let Some(start) = rest.find("<article") {
+    let Some(open_end) = rest[start..].find('>') else {
+      break;
+    };
+    let content_start = start + open_end + 1;
+    let Some(end) = rest[content_start..].find("</article>") else {
+      break;
+    };
+    articles.push(&rest[content_start..content_start + end]);
+    rest = &rest[content_start + end..];
+  }
+  articles
+}
+
+/// Converts the html of a puzzle description to markdown. Only handles the tags that puzzle
+/// descriptions use: headings, paragraphs, emphasis, code, lists and links.
+pub fn html_to_markdown(html: &str) -> String {
+  let mut out = String::new();
+  let mut in_pre = false;
+  let mut lists = 0;
+  let mut links: Vec<String> = vec![];
+  let mut rest = html;
+
+  while !rest.is_empty() {
+    let Some(tag_start) = rest.find('<') else {
+      push_text(&mut out, rest, in_pre);
+      break;
+    };
+    push_text(&mut out, &rest[..tag_start], in_pre);
+    let Some(tag_end) = rest[tag_start..].find('>').map(|i| tag_start + i) else {
+      break;
+    };
+    let tag = &rest[tag_start + 1..tag_end];
+    rest = &rest[tag_end + 1..];
+
+    let closing = tag.starts_with('/');
+    let name = tag
+      .trim_start_matches('/')
+      .split(|c: char| c.is_whitespace() || c == '/')
+      .next()
+      .unwrap_or_default();
+
+    match (name, closing) {
+      ("h2", false) => out.push_str("## "),
+      ("h2" | "p", true) => out.push_str("\n\n"),
+      ("em", _) if !in_pre => out.push('*'),
+      ("strong", _) if !in_pre => out.push_str("**"),
+      ("code", _) if !in_pre => out.push('`'),
+      ("pre", false) => {
+        in_pre = true;
+        out.push_str("```\n");
+      }
+      ("pre", true) => {
+        in_pre = false;
+        if !out.ends_with('\n') {
+          out.push('\n');
+        }
+        out.push_str("```\n\n");
+      }
+      ("ul", false) => lists += 1,
+      ("ul", true) => {
+        lists -= 1;
+        if lists == 0 {
+          out.push('\n');
+        }
+      }
+      ("li", false) => {
+        if !out.is_empty() && !out.ends_with('\n') {
+          out.push('\n');
+        }
+        out.push_str(&"  ".repeat(lists.max(1) - 1));
+        out.push_str("- ");
+      }
+      ("li", true) if !out.ends_with('\n') => out.push('\n'),
+      ("a", false) => {
+        links.push(attribute(tag, "href").unwrap_or_default());
+        out.push('[');
+      }
+      ("a", true) => {
+        let href = links.pop().unwrap_or_default();
+        out.push_str(&format!("]({})", href));
+      }
+      _ => {}
+    }
+  }
+
+  let mut markdown = out.trim().to_string();
+  while markdown.contains("\n\n\n") {
+    markdown = markdown.replace("\n\n\n", "\n\n");
+  }
+  markdown
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+  let text = decode_entities(text);
+  if in_pre {
+    out.push_str(&text);
+  } else if !text.trim().is_empty() {
+    out.push_str(&text.replace('\n', " "));
+  } else if !text.is_empty() && !out.is_empty() && !out.ends_with(['\n', ' ']) {
+    out.push(' ');
+  }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+  let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+  let end = start + tag[start..].find('"')?;
+  Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(text: &str) -> String {
+  let mut out = String::with_capacity(text.len());
+  let mut rest = text;
+  while let Some(start) = rest.find('&') {
+    out.push_str(&rest[..start]);
+    rest = &rest[start..];
+    let decoded = rest.find(';').and_then(|end| {
+      let c = match &rest[1..end] {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        entity => {
+          let code = match entity.strip_prefix("#x") {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => entity.strip_prefix('#')?.parse().ok()?,
+          };
+          char::from_u32(code)?
+        }
+      };
+      Some((c, end))
+    });
+    match decoded {
+      Some((c, end)) => {
+        out.push(c);
+        rest = &rest[end + 1..];
+      }
+      None => {
+        out.push('&');
+        rest = &rest[1..];
+      }
+    }
+  }
+  out.push_str(rest);
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::{BufRead, BufReader, Read, Write};
+  use std::net::TcpListener;
+  use std::thread;
+
+  /// Answers a single request with `status` and `body`, returns the url of the server and the
+  /// request it received.
+  fn serve_once(status: &str, body: &str) -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let response = format!(
+      "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+      status,
+      body.len(),
+      body
+    );
+
+    let handle = thread::spawn(move || {
+      let (mut stream, _) = listener.accept().unwrap();
+      let mut request = String::new();
+      let mut reader = BufReader::new(stream.try_clone().unwrap());
+      // the headers end with an empty line.
+      while reader.read_line(&mut request).unwrap() > 2 {}
+      let length = request
+        .to_lowercase()
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length: ")?.parse().ok())
+        .unwrap_or(0);
+      let mut body = vec![0; length];
+      reader.read_exact(&mut body).unwrap();
+      stream.write_all(response.as_bytes()).unwrap();
+      request + &String::from_utf8(body).unwrap()
+    });
+    (url, handle)
+  }
+
+  #[test]
+  fn test_input() {
+    let (url, server) = serve_once("200 OK", "1000\n2000\n");
+    let input = Client::new(&url, "abc").input(2022, 1).unwrap();
+    assert_eq!(input, "1000\n2000\n");
+
+    let request = server.join().unwrap().to_lowercase();
+    assert!(request.starts_with("get /2022/day/1/input http/1.1\r\n"));
+    assert!(request.contains("\r\ncookie: session=abc\r\n"));
+    assert!(request.contains(&format!(
+      "\r\nuser-agent: {}\r\n",
+      user_agent().to_lowercase()
+    )));
+  }
+
+  #[test]
+  fn test_errors() {
+    let (url, _) = serve_once("400 Bad Request", "Puzzle inputs differ by user.");
+    let error = Client::new(&url, "expired").input(2022, 1).unwrap_err();
+    assert!(matches!(error, ClientError::Unauthorized));
+
+    let (url, _) = serve_once("404 Not Found", "");
+    let error = Client::new(&url, "abc").puzzle(2022, 25).unwrap_err();
+    assert!(matches!(
+      error,
+      ClientError::NotFound {
+        year: 2022,
+        day: 25
+      }
+    ));
+
+    let (url, _) = serve_once("200 OK", "<main><p>Nothing here</p></main>");
+    let error = Client::new(&url, "abc").puzzle(2022, 1).unwrap_err();
+    assert!(matches!(error, ClientError::NoPuzzle));
+  }
+
+  #[test]
+  fn test_puzzle() {
+    let page = "<main>\n<article class=\"day-desc\"><h2>--- Day 1: Calorie Counting ---</h2><p>The <em>Elves</em> take turns.</p>\n</article>\n<p>Your puzzle answer was <code>1</code>.</p><article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Sum &lt;three&gt;.</p></article></main>";
+    let (url, _) = serve_once("200 OK", page);
+    assert_eq!(
+      Client::new(&url, "abc").puzzle(2022, 1).unwrap(),
+      "## --- Day 1: Calorie Counting ---\n\nThe *Elves* take turns.\n\n## --- Part Two ---\n\nSum <three>.\n"
+    );
+  }
+
+  #[test]
+  fn test_submit() {
+    let page = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.</p></article></main>";
+    let (url, server) = serve_once("200 OK", page);
+    let outcome = Client::new(&url, "abc").submit(2022, 5, 2, "MCD").unwrap();
+    assert_eq!(outcome, Outcome::Correct);
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+    assert!(request.ends_with("\r\n\r\nlevel=2&answer=MCD"));
+  }
+
+  #[test]
+  fn test_outcome() {
+    let outcome = |text: &str| Outcome::from_html(&format!("<article><p>{}</p></article>", text));
+
+    assert_eq!(
+      outcome("That's not the right answer; your answer is too high.  If you're stuck, ..."),
+      Outcome::TooHigh
+    );
+    assert_eq!(
+      outcome("That's not the right answer; your answer is too low."),
+      Outcome::TooLow
+    );
+    assert_eq!(
+      outcome("That's not the right answer.  If you're stuck, ..."),
+      Outcome::Incorrect
+    );
+    assert_eq!(
+      outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+      Outcome::RateLimited(Duration::from_secs(65))
+    );
+    assert_eq!(
+      outcome("You don't seem to be solving the right level.  Did you already complete it?"),
+      Outcome::AlreadySolved
+    );
+    assert_eq!(outcome("Huh?"), Outcome::Unknown("Huh?".into()));
+  }
+
+  #[test]
+  fn test_html_to_markdown() {
+    let html = "<p>For example, <a href=\"/2022/about\" target=\"_blank\">read this</a>:</p>\n<pre><code>1000\n<em>2000</em>\n</code></pre>\n<ul>\n<li>The <code>first</code> Elf.</li>\n<li>A &quot;second&quot; one&#39;s.</li>\n</ul>\n<p>Done &amp; <span title=\"hi\">dusted</span>.</p>";
+    assert_eq!(
+      html_to_markdown(html),
+      "For example, [read this](/2022/about):\n\n```\n1000\n2000\n```\n\n- The `first` Elf.\n- A \"second\" one's.\n\nDone & dusted."
+    );
+  }
+}
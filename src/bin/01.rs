@@ -19,25 +19,11 @@ pub fn part_two(input: &str) -> Option<u32> {
   )
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 1);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(1);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 1);
-    assert_eq!(part_one(&input), Some(24000));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 1);
-    assert_eq!(part_two(&input), Some(45000));
-  }
+  advent_of_code::aoc_tests!(1, part_one => 24000, part_two => 45000);
 }
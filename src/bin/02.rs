@@ -44,25 +44,11 @@ pub fn part_two(input: &str) -> Option<u32> {
   )
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 2);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(2);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 2);
-    assert_eq!(part_one(&input), Some(15));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 2);
-    assert_eq!(part_two(&input), Some(12));
-  }
+  advent_of_code::aoc_tests!(2, part_one => 15, part_two => 12);
 }
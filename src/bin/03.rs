@@ -13,8 +13,8 @@ pub fn part_one(input: &str) -> Option<u32> {
         // Find the intersection of the two sets
         let z = x.intersection(&y).collect::<Vec<&char>>();
 
-        // x.get(0) to u32 a-z = 1-26 and A-Z = 27-52
-        z.get(0)
+        // z.first() to u32 a-z = 1-26 and A-Z = 27-52
+        z.first()
           .map(|c| {
             if c.is_lowercase() {
               (c.to_ascii_uppercase() as u32) - 64
@@ -61,25 +61,11 @@ pub fn part_two(input: &str) -> Option<u32> {
   )
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 3);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(3);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 3);
-    assert_eq!(part_one(&input), Some(157));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 3);
-    assert_eq!(part_two(&input), Some(70));
-  }
+  advent_of_code::aoc_tests!(3, part_one => 157, part_two => 70);
 }
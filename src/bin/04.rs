@@ -11,7 +11,6 @@ fn parse_sections(value: &str) -> (u32, u32) {
 fn parse_pairs(input: &str) -> Vec<((u32, u32), (u32, u32))> {
   input
     .split('\n')
-    .into_iter()
     .map(|line| line.split(',').collect_vec())
     .map(|x| {
       x.into_iter()
@@ -48,25 +47,11 @@ pub fn part_two(input: &str) -> Option<u32> {
   )
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 4);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(4);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 4);
-    assert_eq!(part_one(&input), Some(2));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 4);
-    assert_eq!(part_two(&input), Some(4));
-  }
+  advent_of_code::aoc_tests!(4, part_one => 2, part_two => 4);
 }
@@ -1,79 +1,109 @@
+use advent_of_code::AocError;
 use itertools::Itertools;
 
-fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
-  assert!(!v.is_empty());
-  let len = v[0].len();
-  let mut iters: Vec<_> = v.into_iter().map(|n| n.into_iter()).collect();
+/// Turns the rows of crates into stacks. Short rows, e.g. without trailing spaces, have no crates
+/// on the missing stacks.
+fn transpose(v: Vec<Vec<char>>) -> Vec<Vec<char>> {
+  let len = v.iter().map(Vec::len).max().unwrap_or_default();
   (0..len)
-    .map(|_| {
-      iters
-        .iter_mut()
-        .map(|n| n.next().unwrap())
-        .collect::<Vec<T>>()
+    .map(|i| {
+      v.iter()
+        .map(|row| row.get(i).copied().unwrap_or(' '))
+        .collect::<Vec<char>>()
     })
     .collect()
 }
 
-fn parse_stack_line(line: &str) -> Vec<char> {
+fn parse_stack_line(input: &str, line: &str) -> Result<Vec<char>, AocError> {
   line
     .chars()
     .chunks(4)
     .into_iter()
-    .map(|mut chunk| chunk.nth(1).unwrap())
-    .collect_vec()
+    .map(|mut chunk| {
+      chunk
+        .nth(1)
+        .ok_or_else(|| AocError::at_line(input, line, "expected crates like `[A]`, one per stack"))
+    })
+    .collect()
 }
 
-fn parse_stacks(input: &str) -> Vec<Vec<char>> {
-  transpose(
-    input
-      .lines()
-      .filter(|line| line.contains('['))
-      .map(parse_stack_line)
-      .rev()
+fn parse_stacks(input: &str, stacks: &str) -> Result<Vec<Vec<char>>, AocError> {
+  let rows = stacks
+    .lines()
+    .filter(|line| line.contains('['))
+    .map(|line| parse_stack_line(input, line))
+    .collect::<Result<Vec<_>, _>>()?;
+  if rows.is_empty() {
+    return Err(AocError::new(
+      "expected at least one row of crates like `[A]`",
+    ));
+  }
+
+  Ok(
+    transpose(rows.into_iter().rev().collect_vec())
+      .into_iter()
+      .map(|line| line.into_iter().filter(|c| *c != ' ').collect_vec())
       .collect_vec(),
   )
-  .into_iter()
-  .map(|line| line.into_iter().filter(|c| *c != ' ').collect_vec())
-  .collect_vec()
 }
 
-fn parse_instructions(input: &str) -> Vec<(usize, usize, usize)> {
-  input
+type Instructions = Vec<(usize, usize, usize)>;
+
+fn parse_instructions(input: &str, instructions: &str) -> Result<Instructions, AocError> {
+  instructions
     .lines()
     .map(|line| {
       line
         .split(' ')
         .filter_map(|i| i.parse().ok())
         .collect_tuple::<(usize, usize, usize)>()
-        .unwrap()
+        .filter(|(_, from, to)| *from > 0 && *to > 0)
+        .ok_or_else(|| AocError::at_line(input, line, "expected `move <n> from <n> to <n>`"))
     })
-    .collect_vec()
+    .collect()
+}
+
+fn parse(input: &str) -> Result<(Vec<Vec<char>>, Instructions), AocError> {
+  let (stacks_input, instructions_input) =
+    input.split("\n\n").collect_tuple().ok_or_else(|| {
+      AocError::new("expected the stacks and the instructions separated by an empty line")
+    })?;
+
+  Ok((
+    parse_stacks(input, stacks_input)?,
+    parse_instructions(input, instructions_input)?,
+  ))
 }
 
-fn last_n(stacks: &mut Vec<char>, at: usize) -> Vec<char> {
+fn stack(stacks: &mut [Vec<char>], number: usize) -> Result<&mut Vec<char>, AocError> {
+  stacks
+    .get_mut(number - 1)
+    .ok_or_else(|| AocError::new(format!("there is no stack {}", number)))
+}
+
+fn last_n(stacks: &mut Vec<char>, at: usize) -> Option<Vec<char>> {
   let mut new = Vec::new();
 
   for _ in 0..at {
-    new.push(stacks.pop().unwrap());
+    new.push(stacks.pop()?);
   }
 
   new.reverse();
-  new
+  Some(new)
 }
 
-pub fn part_one(input: &str) -> Option<String> {
-  let (stacks_input, instructions_input) = input.split("\n\n").collect_tuple().unwrap();
-  let mut stacks = parse_stacks(stacks_input);
-  parse_instructions(instructions_input)
-    .into_iter()
-    .for_each(|(amount, from, to)| {
-      for _ in 0..amount {
-        let value = stacks.get_mut(from - 1).unwrap().pop().unwrap();
-        stacks.get_mut(to - 1).unwrap().push(value)
-      }
-    });
-
-  Some(
+pub fn part_one(input: &str) -> Result<String, AocError> {
+  let (mut stacks, instructions) = parse(input)?;
+  for (amount, from, to) in instructions {
+    for _ in 0..amount {
+      let value = stack(&mut stacks, from)?
+        .pop()
+        .ok_or_else(|| AocError::new(format!("stack {} is empty", from)))?;
+      stack(&mut stacks, to)?.push(value)
+    }
+  }
+
+  Ok(
     stacks
       .into_iter()
       .filter_map(|stack| stack.last().cloned())
@@ -81,17 +111,15 @@ pub fn part_one(input: &str) -> Option<String> {
   )
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-  let (stacks_input, instructions_input) = input.split("\n\n").collect_tuple().unwrap();
-  let mut stacks = parse_stacks(stacks_input);
-  parse_instructions(instructions_input)
-    .into_iter()
-    .for_each(|(amount, from, to)| {
-      let mut value = last_n(stacks.get_mut(from - 1).unwrap(), amount);
-      stacks.get_mut(to - 1).unwrap().append(&mut value)
-    });
+pub fn part_two(input: &str) -> Result<String, AocError> {
+  let (mut stacks, instructions) = parse(input)?;
+  for (amount, from, to) in instructions {
+    let mut value = last_n(stack(&mut stacks, from)?, amount)
+      .ok_or_else(|| AocError::new(format!("stack {} has less than {} crates", from, amount)))?;
+    stack(&mut stacks, to)?.append(&mut value)
+  }
 
-  Some(
+  Ok(
     stacks
       .into_iter()
       .filter_map(|stack| stack.last().cloned())
@@ -99,25 +127,32 @@ pub fn part_two(input: &str) -> Option<String> {
   )
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 5);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(5);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 5);
-    assert_eq!(part_one(&input), Some("CMZ".to_owned()));
-  }
+  advent_of_code::aoc_tests!(5, part_one => "CMZ".to_owned(), part_two => "MCD".to_owned());
 
   #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 5);
-    assert_eq!(part_two(&input), Some("MCD".to_owned()));
+  fn test_malformed_input() {
+    let error = part_one(" 1   2 \n\nmove 1 from 1 to 2").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "expected at least one row of crates like `[A]`"
+    );
+
+    let error = part_two("[A] [B] [\n 1   2   3\n\nmove 1 from 1 to 2").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 1: expected crates like `[A]`, one per stack"
+    );
+
+    // short rows just have no crates on the last stacks.
+    assert_eq!(
+      part_one("[A]\n[B] [C]\n 1   2\n\nmove 1 from 1 to 2"),
+      Ok("BA".to_owned())
+    );
   }
 }
@@ -20,11 +20,7 @@ pub fn part_two(input: &str) -> Option<usize> {
   Some(solution(input, 14))
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 6);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(6);
 
 #[cfg(test)]
 mod tests {
@@ -32,7 +28,7 @@ mod tests {
 
   #[test]
   fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 6);
+    let input = advent_of_code::read_file("examples", 6).unwrap();
     input
       .split('\n')
       .map(|line| line.split(' ').collect_tuple().unwrap())
@@ -41,10 +37,12 @@ mod tests {
 
   #[test]
   fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 6);
+    let input = advent_of_code::read_file("examples", 6).unwrap();
     input
       .split('\n')
       .map(|line| line.split(' ').collect_tuple().unwrap())
       .for_each(|(i, _, answer)| assert_eq!(part_two(i), answer.parse().ok()));
   }
+
+  advent_of_code::aoc_tests!(6);
 }
@@ -123,25 +123,11 @@ pub fn part_two(input: &str) -> Option<u32> {
   Some(delete_node.size())
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 7);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(7);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 7);
-    assert_eq!(part_one(&input), Some(95437));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 7);
-    assert_eq!(part_two(&input), Some(24933642));
-  }
+  advent_of_code::aoc_tests!(7, part_one => 95437, part_two => 24933642);
 }
@@ -19,7 +19,7 @@ fn height_at(pos: (usize, usize), map: &[Vec<u32>]) -> Option<&u32> {
   }
 }
 
-fn visible(map: &Vec<Vec<u32>>) -> impl Fn((usize, usize)) -> bool + '_ {
+fn visible(map: &[Vec<u32>]) -> impl Fn((usize, usize)) -> bool + '_ {
   move |(x, y)| {
     let size = map.len();
 
@@ -30,12 +30,11 @@ fn visible(map: &Vec<Vec<u32>>) -> impl Fn((usize, usize)) -> bool + '_ {
       (x + 1..size).map(|v| (v, y)).collect(),
     ]
     .iter()
-    .map(|coords: &Vec<(usize, usize)>| {
+    .any(|coords: &Vec<(usize, usize)>| {
       coords
         .iter()
         .all(|pos| height_at(*pos, map).unwrap() < height_at((x, y), map).unwrap())
     })
-    .any(|b| b)
   }
 }
 
@@ -86,29 +85,6 @@ pub fn part_two(input: &str) -> Option<u32> {
   GridIter::new(map.len(), map.len()).map(score(&map)).max()
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 8);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
-
-#[cfg(test)]
-mod tests {
-  use super::*;
-
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 8);
-    assert_eq!(part_one(&input), Some(21));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 8);
-    assert_eq!(part_two(&input), Some(8));
-  }
-}
-
 pub struct GridIter {
   cur_x: usize,
   cur_y: usize,
@@ -149,3 +125,12 @@ impl Iterator for GridIter {
     }
   }
 }
+
+advent_of_code::main!(8);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  advent_of_code::aoc_tests!(8, part_one => 21, part_two => 8);
+}
@@ -86,25 +86,17 @@ pub fn part_two(input: &str) -> Option<usize> {
   ))
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 9);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(9);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 9);
-    assert_eq!(part_one(&input), Some(87));
+  advent_of_code::example_tests! {
+    day: 9,
+    part_one: { a => 13, b => 87 },
+    part_two: { a => 1, b => 36 },
   }
 
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 9);
-    assert_eq!(part_two(&input), Some(36));
-  }
+  advent_of_code::aoc_tests!(9);
 }
@@ -1,29 +1,51 @@
+use advent_of_code::AocError;
 use itertools::Itertools;
 
-fn parse<C, F>(input: &str, init: (i32, i32, Vec<C>), f: F) -> Vec<C>
+/// The value added by an instruction, `None` for `noop`.
+fn parse_line(input: &str, line: &str) -> Result<Option<i32>, AocError> {
+  match *line.split(' ').collect_vec() {
+    ["noop"] => Ok(None),
+    ["addx", v] => v
+      .parse()
+      .map(Some)
+      .map_err(|_| AocError::at(input, v, format!("expected a number, found `{}`", v))),
+    _ => Err(AocError::at_line(
+      input,
+      line,
+      "expected `noop` or `addx <n>`",
+    )),
+  }
+}
+
+fn parse<C, F>(input: &str, init: (i32, i32, Vec<C>), f: F) -> Result<Vec<C>, AocError>
 where
   F: FnMut((i32, i32, Vec<C>), &i32) -> (i32, i32, Vec<C>),
 {
-  input
-    .lines()
-    .map(|op| match *op.split(' ').collect_vec() {
-      ["addx", v] => vec![0, v.parse::<i32>().unwrap()],
-      _ => vec![0],
-    })
-    .concat()
-    .iter()
-    .fold(init, f)
-    .2
+  Ok(
+    input
+      .lines()
+      .map(|line| {
+        Ok(match parse_line(input, line)? {
+          Some(v) => vec![0, v],
+          None => vec![0],
+        })
+      })
+      .collect::<Result<Vec<_>, AocError>>()?
+      .concat()
+      .iter()
+      .fold(init, f)
+      .2,
+  )
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
-  Some(
+pub fn part_one(input: &str) -> Result<i32, AocError> {
+  Ok(
     parse(input, (1, 1, vec![]), |(x, pc, mut vals), op| {
       if pc % 40 == 20 {
         vals.push(x * pc);
       }
       (x + op, pc + 1, vals)
-    })
+    })?
     .iter()
     .sum(),
   )
@@ -31,18 +53,18 @@ pub fn part_one(input: &str) -> Option<i32> {
 
 fn do_op(x: &mut i32, pc: &mut i32, val: i32, output: &mut [Vec<char>], width: usize) {
   let distance = i32::abs(((*pc - 1) % 40) - *x);
-  if distance < 2 {
-    *output
-      .get_mut((*pc as usize - 1) / width)
-      .unwrap()
-      .get_mut((*pc as usize - 1) % width)
-      .unwrap() = '█';
+  // cycles after the last row of the screen draw nothing.
+  let pixel = output
+    .get_mut((*pc as usize - 1) / width)
+    .and_then(|row| row.get_mut((*pc as usize - 1) % width));
+  if let (true, Some(pixel)) = (distance < 2, pixel) {
+    *pixel = '█';
   }
   *x += val;
   *pc += 1;
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(input: &str) -> Result<String, AocError> {
   let width = 40;
   let height = 6;
   let mut output = vec![vec![' '; width]; height];
@@ -50,17 +72,14 @@ pub fn part_two(input: &str) -> Option<String> {
   let mut pc = 1;
 
   for line in input.lines() {
-    let val = match *line.split(' ').collect_vec() {
-      ["addx", v] => v.parse::<i32>().unwrap(),
-      _ => 0,
-    };
+    let val = parse_line(input, line)?;
     do_op(&mut x, &mut pc, 0, &mut output, width);
-    if val != 0 {
+    if let Some(val) = val {
       do_op(&mut x, &mut pc, val, &mut output, width);
     }
   }
 
-  Some(
+  Ok(
     output
       .iter()
       .map(|l| l.iter().collect::<String>())
@@ -68,25 +87,30 @@ pub fn part_two(input: &str) -> Option<String> {
   )
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 10);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(10);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 10);
-    assert_eq!(part_one(&input), Some(13140));
-  }
+  advent_of_code::aoc_tests!(
+    10,
+    part_one => 13140,
+    part_two => "██  ██  ██  ██  ██  ██  ██  ██  ██  ██  \n███   ███   ███   ███   ███   ███   ███ \n████    ████    ████    ████    ████    \n█████     █████     █████     █████     \n██████      ██████      ██████      ████\n███████       ███████       ███████     ".to_string(),
+  );
 
   #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 10);
-    assert_eq!(part_two(&input), Some("██  ██  ██  ██  ██  ██  ██  ██  ██  ██  \n███   ███   ███   ███   ███   ███   ███ \n████    ████    ████    ████    ████    \n█████     █████     █████     █████     \n██████      ██████      ██████      ████\n███████       ███████       ███████     ".to_string()));
+  fn test_malformed_input() {
+    let error = part_one("noop\naddx x").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 2, column 6: expected a number, found `x`"
+    );
+
+    let error = part_two("noop\nmulx 3").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 2: expected `noop` or `addx <n>`"
+    );
   }
 }
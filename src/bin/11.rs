@@ -70,7 +70,7 @@ fn solve(input: &str, loop_count: u32, divide_by: u64) -> u64 {
       while let Some(item) = monkeys[i].items.pop_front() {
         let item = (monkeys[i].apply_op(item) % mod_to_rule_them_all) / divide_by;
 
-        let throw_to = monkeys[i].throw_to[usize::from(item % monkeys[i].test == 0)];
+        let throw_to = monkeys[i].throw_to[usize::from(item.is_multiple_of(monkeys[i].test))];
 
         monkeys[throw_to].items.push_back(item);
         monkeys[i].items_handeled += 1;
@@ -94,25 +94,11 @@ pub fn part_two(input: &str) -> Option<u64> {
   Some(solve(input, 10000, 1))
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 11);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(11);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 11);
-    assert_eq!(part_one(&input), Some(10605));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 11);
-    assert_eq!(part_two(&input), Some(2713310158));
-  }
+  advent_of_code::aoc_tests!(11, part_one => 10605, part_two => 2713310158);
 }
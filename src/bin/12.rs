@@ -1,7 +1,9 @@
+use advent_of_code::solution::Solution;
+use advent_of_code::AocError;
 use std::collections::VecDeque;
 
 #[derive(Debug)]
-struct ElevationMap {
+pub struct ElevationMap {
   heights: Vec<Vec<u32>>,
   start: (usize, usize),
   end: (usize, usize),
@@ -17,40 +19,68 @@ impl AsNumber for char {
   }
 }
 
-fn parse(input: &str) -> (ElevationMap, usize, usize) {
+fn parse(input: &str) -> Result<(ElevationMap, usize, usize), AocError> {
   let mut elevations = ElevationMap {
     start: (0, 0),
     end: (0, 0),
     heights: Vec::new(),
   };
+  let (mut has_start, mut has_end) = (false, false);
   for (index, line) in input.lines().enumerate() {
+    if elevations
+      .heights
+      .first()
+      .map_or(line.is_empty(), |first| first.len() != line.chars().count())
+    {
+      return Err(AocError::at_line(
+        input,
+        line,
+        "expected a row of heights as long as the first one",
+      ));
+    }
     let mut row = Vec::new();
-    for (jndex, c) in line.chars().enumerate() {
+    for (jndex, (offset, c)) in line.char_indices().enumerate() {
       match c {
         'S' => {
           elevations.start = (index, jndex);
+          has_start = true;
           row.push('a'.as_number());
         }
         'E' => {
           elevations.end = (index, jndex);
+          has_end = true;
           row.push('z'.as_number());
         }
-        _ => {
+        'a'..='z' => {
           row.push(c.as_number());
         }
+        _ => {
+          return Err(AocError::at(
+            input,
+            &line[offset..offset + c.len_utf8()],
+            format!(
+              "unexpected `{}`, expected a height from `a` to `z`, `S` or `E`",
+              c
+            ),
+          ));
+        }
       }
     }
     elevations.heights.push(row);
   }
 
+  if !has_start || !has_end {
+    return Err(AocError::new("expected a start `S` and an end `E`"));
+  }
+
   let size_x = elevations.heights.len();
   let size_y = elevations.heights[0].len();
 
-  (elevations, size_x, size_y)
+  Ok((elevations, size_x, size_y))
 }
 
 fn search_until_condition<F>(
-  (elevations, size_x, size_y): (ElevationMap, usize, usize),
+  &(ref elevations, size_x, size_y): &(ElevationMap, usize, usize),
   condition: F,
 ) -> Option<u32>
 where
@@ -81,7 +111,7 @@ where
   positions.push_back(elevations.end);
 
   while let Some((i, j)) = positions.pop_front() {
-    if condition((i, j), &elevations) {
+    if condition((i, j), elevations) {
       return steps_to_pos[i][j];
     }
     let current_steps = steps_to_pos[i][j].unwrap_or(u32::MAX - 1);
@@ -99,33 +129,51 @@ where
   None
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-  search_until_condition(parse(input), |pos, map| pos == map.start)
-}
+pub struct Day12;
 
-pub fn part_two(input: &str) -> Option<u32> {
-  search_until_condition(parse(input), |(i, j), map| map.heights[i][j] == 0)
-}
+impl Solution for Day12 {
+  const DAY: u8 = 12;
+
+  type Parsed<'a> = (ElevationMap, usize, usize);
+  type PartOne = Option<u32>;
+  type PartTwo = Option<u32>;
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 12);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
+  fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+    parse(input)
+  }
+
+  fn part_one(parsed: &Self::Parsed<'_>) -> Option<u32> {
+    search_until_condition(parsed, |pos, map| pos == map.start)
+  }
+
+  fn part_two(parsed: &Self::Parsed<'_>) -> Option<u32> {
+    search_until_condition(parsed, |(i, j), map| map.heights[i][j] == 0)
+  }
 }
 
+advent_of_code::solution!(Day12);
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 12);
-    assert_eq!(part_one(&input), Some(31));
-  }
+  advent_of_code::aoc_tests!(12, part_one => 31, part_two => 29);
 
   #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 12);
-    assert_eq!(part_two(&input), Some(29));
+  fn test_malformed_input() {
+    let error = Day12::parse("").unwrap_err();
+    assert_eq!(error.to_string(), "expected a start `S` and an end `E`");
+
+    let error = Day12::parse("Sbc\n\nabE").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 2: expected a row of heights as long as the first one"
+    );
+
+    let error = Day12::parse("Sbc\nab!").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 2, column 3: unexpected `!`, expected a height from `a` to `z`, `S` or `E`"
+    );
   }
 }
@@ -54,18 +54,18 @@ impl AsNum for char {
 
 impl PartialOrd for Node {
   fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-    match (self, other) {
-      (Node::Num(x), Node::Num(y)) => x.partial_cmp(y),
-      (Node::List(x), Node::List(y)) => x.partial_cmp(y),
-      (Node::Num(_), Node::List(_)) => Node::List(vec![self.clone()]).partial_cmp(other),
-      (Node::List(_), Node::Num(_)) => self.partial_cmp(&Node::List(vec![other.clone()])),
-    }
+    Some(self.cmp(other))
   }
 }
 
 impl Ord for Node {
   fn cmp(&self, other: &Self) -> Ordering {
-    self.partial_cmp(other).unwrap()
+    match (self, other) {
+      (Node::Num(x), Node::Num(y)) => x.cmp(y),
+      (Node::List(x), Node::List(y)) => x.cmp(y),
+      (Node::Num(_), Node::List(_)) => Node::List(vec![self.clone()]).cmp(other),
+      (Node::List(_), Node::Num(_)) => self.cmp(&Node::List(vec![other.clone()])),
+    }
   }
 }
 
@@ -84,7 +84,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 
 pub fn part_two(input: &str) -> Option<usize> {
   Some(
-    vec![input.lines().collect(), vec!["[[2]]", "[[6]]"]]
+    [input.lines().collect(), vec!["[[2]]", "[[6]]"]]
       .concat()
       .iter()
       .filter(|l| !l.is_empty())
@@ -99,25 +99,11 @@ pub fn part_two(input: &str) -> Option<usize> {
   )
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 13);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(13);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 13);
-    assert_eq!(part_one(&input), Some(13));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 13);
-    assert_eq!(part_two(&input), Some(140));
-  }
+  advent_of_code::aoc_tests!(13, part_one => 13, part_two => 140);
 }
@@ -155,25 +155,11 @@ pub fn part_two(input: &str) -> Option<u32> {
   Some(count)
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 14);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(14);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 14);
-    assert_eq!(part_one(&input), Some(24));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 14);
-    assert_eq!(part_two(&input), Some(93));
-  }
+  advent_of_code::aoc_tests!(14, part_one => 24, part_two => 93);
 }
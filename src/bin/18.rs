@@ -1,3 +1,5 @@
+use advent_of_code::solution::Solution;
+use advent_of_code::AocError;
 use itertools::Itertools;
 use std::collections::VecDeque;
 
@@ -8,7 +10,7 @@ fn add(pos: Pos, other: Pos) -> Pos {
 }
 
 fn offsets(pos: &Pos) -> Vec<Pos> {
-  vec![
+  [
     (0, 0, 1),
     (0, 1, 0),
     (1, 0, 0),
@@ -17,11 +19,11 @@ fn offsets(pos: &Pos) -> Vec<Pos> {
     (-1, 0, 0),
   ]
   .iter()
-  .map(|off| (add(*off, *pos)))
+  .map(|off| add(*off, *pos))
   .collect_vec()
 }
 
-fn parse(input: &str) -> (Pos, Pos, Vec<Pos>) {
+fn parse(input: &str) -> Result<(Pos, Pos, Vec<Pos>), AocError> {
   let mut min = (0, 0, 0);
   let mut max = (0, 0, 0);
 
@@ -30,9 +32,15 @@ fn parse(input: &str) -> (Pos, Pos, Vec<Pos>) {
     .map(|line| {
       let (x, y, z) = line
         .split(',')
-        .map(|num| num.parse().unwrap())
+        .map(|num| {
+          num
+            .parse()
+            .map_err(|_| AocError::at(input, num, format!("expected a number, found `{}`", num)))
+        })
+        .collect::<Result<Vec<i32>, _>>()?
+        .into_iter()
         .collect_tuple()
-        .unwrap();
+        .ok_or_else(|| AocError::at_line(input, line, "expected `<x>,<y>,<z>`"))?;
 
       if x < min.0 {
         min.0 = x
@@ -52,16 +60,14 @@ fn parse(input: &str) -> (Pos, Pos, Vec<Pos>) {
         max.2 = z
       }
 
-      (x, y, z)
+      Ok((x, y, z))
     })
-    .collect_vec();
+    .collect::<Result<Vec<_>, AocError>>()?;
 
-  (min, max, blocks)
+  Ok((min, max, blocks))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-  let (_, _, blocks) = parse(input);
-
+fn surface_area((_, _, blocks): &(Pos, Pos, Vec<Pos>)) -> Option<u32> {
   Some(
     blocks
       .iter()
@@ -83,9 +89,7 @@ fn within_bounds(min: Pos, max: Pos, pos: Pos) -> bool {
     && pos.2 >= min.2
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-  let (min, max, blocks) = parse(input);
-
+fn external_surface_area(&(min, max, ref blocks): &(Pos, Pos, Vec<Pos>)) -> Option<u32> {
   let mut visited: Vec<Pos> = Vec::new();
   let min = (min.0 - 1, min.1 - 1, min.2 - 1);
   let max = (max.0 + 1, max.1 + 1, max.2 + 1);
@@ -113,25 +117,48 @@ pub fn part_two(input: &str) -> Option<u32> {
   Some(external_surface)
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 18);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
+pub struct Day18;
+
+impl Solution for Day18 {
+  const DAY: u8 = 18;
+
+  type Parsed<'a> = (Pos, Pos, Vec<Pos>);
+  type PartOne = Option<u32>;
+  type PartTwo = Option<u32>;
+
+  fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+    parse(input)
+  }
+
+  fn part_one(parsed: &Self::Parsed<'_>) -> Option<u32> {
+    surface_area(parsed)
+  }
+
+  fn part_two(parsed: &Self::Parsed<'_>) -> Option<u32> {
+    external_surface_area(parsed)
+  }
 }
 
+advent_of_code::solution!(Day18);
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 18);
-    assert_eq!(part_one(&input), Some(64));
-  }
+  advent_of_code::aoc_tests!(18, part_one => 64, part_two => 58);
 
   #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 18);
-    assert_eq!(part_two(&input), Some(58));
+  fn test_malformed_input() {
+    let error = Day18::parse("2,2,2\n1,2").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 2: expected `<x>,<y>,<z>`"
+    );
+
+    let error = Day18::parse("2,2,2\n1,x,2").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 2, column 3: expected a number, found `x`"
+    );
   }
 }
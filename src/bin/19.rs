@@ -1,27 +1,41 @@
+use advent_of_code::solution::Solution;
+use advent_of_code::AocError;
 use itertools::Itertools;
 
-struct Blueprint {
+pub struct Blueprint {
   robots: [[u16; 4]; 4],
 }
 
-fn parse(input: &str) -> Vec<Blueprint> {
+fn parse(input: &str) -> Result<Vec<Blueprint>, AocError> {
   input
     .lines()
     .map(|line| {
       let x = line.split(' ').collect_vec();
-      let ore_robot_cost = [x[6].parse().unwrap(), 0, 0, 0];
-      let clay_robot_cost = [x[12].parse().unwrap(), 0, 0, 0];
-      let obsidian_robot_cost = [x[18].parse().unwrap(), x[21].parse().unwrap(), 0, 0];
-      let geode_robot_cost = [x[27].parse().unwrap(), 0, x[30].parse().unwrap(), 0];
+      let cost = |i: usize| -> Result<u16, AocError> {
+        let word = x.get(i).ok_or_else(|| {
+          AocError::at_line(
+            input,
+            line,
+            "expected `Blueprint <n>: Each ore robot costs <n> ore. ...`",
+          )
+        })?;
+        word
+          .parse()
+          .map_err(|_| AocError::at(input, word, format!("expected a cost, found `{}`", word)))
+      };
+      let ore_robot_cost = [cost(6)?, 0, 0, 0];
+      let clay_robot_cost = [cost(12)?, 0, 0, 0];
+      let obsidian_robot_cost = [cost(18)?, cost(21)?, 0, 0];
+      let geode_robot_cost = [cost(27)?, 0, cost(30)?, 0];
 
-      Blueprint {
+      Ok(Blueprint {
         robots: [
           ore_robot_cost,
           clay_robot_cost,
           obsidian_robot_cost,
           geode_robot_cost,
         ],
-      }
+      })
     })
     .collect()
 }
@@ -57,12 +71,9 @@ fn simulate(
           Some(0)
         } else if state.robots[ore_type] == 0 {
           // No robot yet, we can't build it (it takes more than max_time to build it).
-          Some(max_time as u16 + 1)
+          Some(max_time + 1)
         } else {
-          Some(
-            (recipe[ore_type] - state.ores[ore_type] + state.robots[ore_type] - 1)
-              / state.robots[ore_type],
-          )
+          Some((recipe[ore_type] - state.ores[ore_type]).div_ceil(state.robots[ore_type]))
         }
       })
       .max()
@@ -101,13 +112,13 @@ fn simulate(
     // We couldn't make new robots, so this is the best this branch can do.
     *max_geodes = std::cmp::max(
       *max_geodes,
-      state.ores[3] + state.robots[3] * (max_time - state.time) as u16,
+      state.ores[3] + state.robots[3] * (max_time - state.time),
     );
   }
 }
 
 fn run_blueprint(blueprint: &Blueprint, max_time: u16) -> OreAmount {
-  let mut max_robots = [u16::max_value(); 4];
+  let mut max_robots = [u16::MAX; 4];
   for i in 0..3 {
     max_robots[i] = blueprint.robots.iter().map(|r| r[i]).max().unwrap();
   }
@@ -126,49 +137,45 @@ fn run_blueprint(blueprint: &Blueprint, max_time: u16) -> OreAmount {
   max_geodes
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-  let blueprints = parse(input);
+pub struct Day19;
 
-  Some(
-    blueprints
-      .iter()
-      .enumerate()
-      .map(|(i, b)| run_blueprint(b, 24) as usize * (i + 1))
-      .sum(),
-  )
-}
+impl Solution for Day19 {
+  const DAY: u8 = 19;
 
-pub fn part_two(input: &str) -> Option<usize> {
-  let blueprints = parse(input);
+  type Parsed<'a> = Vec<Blueprint>;
+  type PartOne = Option<usize>;
+  type PartTwo = Option<usize>;
 
-  Some(
-    blueprints
-      .iter()
-      .take(3)
-      .map(|b| run_blueprint(b, 32) as usize)
-      .product(),
-  )
-}
+  fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+    parse(input)
+  }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 19);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
+  fn part_one(blueprints: &Self::Parsed<'_>) -> Option<usize> {
+    Some(
+      blueprints
+        .iter()
+        .enumerate()
+        .map(|(i, b)| run_blueprint(b, 24) as usize * (i + 1))
+        .sum(),
+    )
+  }
+
+  fn part_two(blueprints: &Self::Parsed<'_>) -> Option<usize> {
+    Some(
+      blueprints
+        .iter()
+        .take(3)
+        .map(|b| run_blueprint(b, 32) as usize)
+        .product(),
+    )
+  }
 }
 
+advent_of_code::solution!(Day19);
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 19);
-    assert_eq!(part_one(&input), None);
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 19);
-    assert_eq!(part_two(&input), None);
-  }
+  advent_of_code::aoc_tests!(19, part_one => 33, part_two => 3472);
 }
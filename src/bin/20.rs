@@ -66,25 +66,11 @@ pub fn part_two(input: &str) -> Option<i64> {
   )
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 20);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(20);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 20);
-    assert_eq!(part_one(&input), Some(3));
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 20);
-    assert_eq!(part_two(&input), None);
-  }
+  advent_of_code::aoc_tests!(20, part_one => 3, part_two => 1623178306);
 }
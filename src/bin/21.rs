@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
+use advent_of_code::solution::Solution;
+use advent_of_code::AocError;
 use itertools::Itertools;
 
 #[derive(Debug)]
-enum Meth<'a> {
+pub enum Meth<'a> {
   Val(f64),
   Add(&'a str, &'a str),
   Sub(&'a str, &'a str),
@@ -28,26 +30,37 @@ fn has_human(name: &str, map: &HashMap<&str, Meth>) -> bool {
   }
 }
 
-fn calculate(name: &str, map: &HashMap<&str, Meth>) -> Result<f64, String> {
+/// Evaluates monkey `name`. If `human` is given, `humn` yells it instead of its own number.
+fn calculate(name: &str, map: &HashMap<&str, Meth>, human: Option<f64>) -> Result<f64, AocError> {
+  if let (Some(value), "humn") = (human, name) {
+    return Ok(value);
+  }
+
   match map.get(name) {
     Some(x) => match x {
       Meth::Val(x) => Ok(*x),
-      Meth::Add(n1, n2) => Ok(calculate(n1, map)? + calculate(n2, map)?),
-      Meth::Sub(n1, n2) => Ok(calculate(n1, map)? - calculate(n2, map)?),
-      Meth::Mul(n1, n2) => Ok(calculate(n1, map)? * calculate(n2, map)?),
-      Meth::Div(n1, n2) => Ok(calculate(n1, map)? / calculate(n2, map)?),
+      Meth::Add(n1, n2) => Ok(calculate(n1, map, human)? + calculate(n2, map, human)?),
+      Meth::Sub(n1, n2) => Ok(calculate(n1, map, human)? - calculate(n2, map, human)?),
+      Meth::Mul(n1, n2) => Ok(calculate(n1, map, human)? * calculate(n2, map, human)?),
+      Meth::Div(n1, n2) => Ok(calculate(n1, map, human)? / calculate(n2, map, human)?),
     },
-    None => Err("Cannot find name".to_string()),
+    None => Err(AocError::new(format!("there is no monkey `{}`", name))),
   }
 }
 
-fn parse(input: &str) -> HashMap<&str, Meth> {
+fn parse_name<'a>(input: &str, name: &'a str) -> Result<&'a str, AocError> {
+  name
+    .strip_suffix(':')
+    .ok_or_else(|| AocError::at(input, name, "expected `<name>:`"))
+}
+
+fn parse(input: &str) -> Result<HashMap<&str, Meth<'_>>, AocError> {
   let mut map: HashMap<&str, Meth> = HashMap::new();
 
   for line in input.lines() {
     match line.split(' ').collect_vec()[..] {
       [name, val1, op, val2] => {
-        let name = &name[0..4];
+        let name = parse_name(input, name)?;
         match op {
           "+" => {
             map.insert(name, Meth::Add(val1, val2));
@@ -62,73 +75,105 @@ fn parse(input: &str) -> HashMap<&str, Meth> {
             map.insert(name, Meth::Div(val1, val2));
           }
           _ => {
-            unreachable!("OP did fucky wucky");
+            return Err(AocError::at(
+              input,
+              op,
+              format!("unknown operation `{}`, expected one of `+ - * /`", op),
+            ));
           }
         }
       }
       [name, val] => {
-        let name = &name[0..4];
-        map.insert(name, Meth::Val(val.parse().unwrap()));
+        let name = parse_name(input, name)?;
+        let val = val
+          .parse()
+          .map_err(|_| AocError::at(input, val, format!("expected a number, found `{}`", val)))?;
+        map.insert(name, Meth::Val(val));
       }
       _ => {
-        unreachable!("Parsing line did fucky wucky")
+        return Err(AocError::at_line(
+          input,
+          line,
+          "expected `<name>: <number>` or `<name>: <name> <op> <name>`",
+        ));
       }
     }
   }
-  map
+  Ok(map)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-  let map = parse(input);
-
-  Some(calculate("root", &map).unwrap() as u64)
+fn root_value(map: &HashMap<&str, Meth>) -> Result<u64, AocError> {
+  Ok(calculate("root", map, None)? as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-  let mut map = parse(input);
-
-  let (humn, other) = match map["root"] {
-    Meth::Add(n1, n2) => {
-      if has_human(n1, &map) {
-        (n1, n2)
-      } else {
-        (n2, n1)
-      }
-    }
-    _ => return None,
+fn human_value(map: &HashMap<&str, Meth>) -> Result<u64, AocError> {
+  // `root` compares its two monkeys, whatever its operation.
+  let (n1, n2) = match map.get("root") {
+    Some(Meth::Add(n1, n2) | Meth::Sub(n1, n2) | Meth::Mul(n1, n2) | Meth::Div(n1, n2)) => (n1, n2),
+    Some(Meth::Val(_)) => return Err(AocError::new("expected `root` to wait for two monkeys")),
+    None => return Err(AocError::new("there is no monkey `root`")),
+  };
+  let (humn, other) = if has_human(n1, map) {
+    (n1, n2)
+  } else if has_human(n2, map) {
+    (n2, n1)
+  } else {
+    return Err(AocError::new(
+      "the number of `root` does not depend on `humn`",
+    ));
   };
 
   let x1 = 1f64;
   let x2 = 100000000000000000f64;
 
-  map.insert("humn", Meth::Val(x1));
-  let first = calculate(humn, &map).unwrap();
+  let first = calculate(humn, map, Some(x1))?;
+  let second = calculate(humn, map, Some(x2))?;
 
-  map.insert("humn", Meth::Val(x2));
-  let second = calculate(humn, &map).unwrap();
-
-  Some(((calculate(other, &map).unwrap() - first) * (x2 - x1) / (second - first) + x1) as u64)
+  Ok(((calculate(other, map, None)? - first) * (x2 - x1) / (second - first) + x1) as u64)
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", 21);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
+pub struct Day21;
+
+impl Solution for Day21 {
+  const DAY: u8 = 21;
+
+  type Parsed<'a> = HashMap<&'a str, Meth<'a>>;
+  type PartOne = Result<u64, AocError>;
+  type PartTwo = Result<u64, AocError>;
+
+  fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+    parse(input)
+  }
+
+  fn part_one(parsed: &Self::Parsed<'_>) -> Result<u64, AocError> {
+    root_value(parsed)
+  }
+
+  fn part_two(parsed: &Self::Parsed<'_>) -> Result<u64, AocError> {
+    human_value(parsed)
+  }
 }
 
+advent_of_code::solution!(Day21);
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", 21);
-    assert_eq!(part_one(&input), Some(152));
-  }
+  advent_of_code::aoc_tests!(21, part_one => 152, part_two => 301);
 
   #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", 21);
-    assert_eq!(part_two(&input), Some(301));
+  fn test_malformed_input() {
+    let error = part_one("root: abcd + efgh\nabcd: 2").unwrap_err();
+    assert_eq!(error.to_string(), "there is no monkey `efgh`");
+
+    let error = part_two("root: 5\nhumn: 2").unwrap_err();
+    assert_eq!(error.to_string(), "expected `root` to wait for two monkeys");
+
+    let error = Day21::parse("root: abcd % efgh").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 1, column 12: unknown operation `%`, expected one of `+ - * /`"
+    );
   }
 }
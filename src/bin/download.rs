@@ -2,20 +2,26 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::aoc_client::{self, Download};
+use advent_of_code::timings::format_date;
 use std::process;
 
 struct Args {
   day: u8,
   year: Option<u16>,
+  /// Downloads files again even if they are unchanged.
+  force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
   let mut args = pico_args::Arguments::from_env();
-  Ok(Args {
+  let parsed = Args {
     day: args.free_from_str()?,
     year: args.opt_value_from_str(["-y", "--year"])?,
-  })
+    force: args.contains("--force"),
+  };
+  advent_of_code::finish_args(args)?;
+  Ok(parsed)
 }
 
 fn main() {
@@ -27,20 +33,30 @@ fn main() {
     }
   };
 
-  if aoc_cli::check().is_err() {
-    eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-    process::exit(1);
-  }
-
-  match aoc_cli::download(args.day, args.year) {
-    Ok(cmd_output) => {
-      if !cmd_output.status.success() {
-        process::exit(1);
-      }
+  match aoc_client::download(args.day, args.year, args.force) {
+    Ok(downloaded) => {
+      report("input", &downloaded.input);
+      report("puzzle", &downloaded.puzzle);
     }
     Err(e) => {
-      eprintln!("failed to spawn aoc-cli: {}", e);
+      eprintln!("Failed to download day {}: {}", args.day, e);
       process::exit(1);
     }
   }
 }
+
+fn report(name: &str, download: &Download) {
+  match download.cached {
+    Some(timestamp) => println!(
+      "🎄 Kept {} \"{}\", it is unchanged since it was downloaded on {}. Use --force to download it again.",
+      name,
+      download.path.display(),
+      format_date(timestamp)
+    ),
+    None => println!(
+      "🎄 Successfully wrote {} to \"{}\".",
+      name,
+      download.path.display()
+    ),
+  }
+}
@@ -0,0 +1,116 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::timings::{self, Trend};
+use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+use std::process;
+
+struct Args {
+  /// Flag parts that got slower than their best run by more than this many percent.
+  threshold: f64,
+  profile: Option<String>,
+  /// Number of most recent runs shown in the trend.
+  runs: usize,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+  let mut args = pico_args::Arguments::from_env();
+  let parsed = Args {
+    threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+    profile: args.opt_value_from_str("--profile")?,
+    runs: args.opt_value_from_str("--runs")?.unwrap_or(10),
+  };
+  advent_of_code::finish_args(args)?;
+  Ok(parsed)
+}
+
+fn print_trend(trend: &Trend, args: &Args) {
+  let latest = trend.latest();
+  let recent = trend
+    .runs
+    .iter()
+    .rev()
+    .take(args.runs)
+    .rev()
+    .map(|e| e.duration)
+    .collect::<Vec<_>>();
+  let slowdown = trend.slowdown();
+
+  print!(
+    "Day {:02}, part {}  {:<10} {:>10.2?} {}({}, {}){}  best: {:>10.2?} {}({}){}",
+    trend.day,
+    trend.part,
+    timings::sparkline(&recent),
+    latest.duration,
+    ANSI_ITALIC,
+    latest.commit,
+    timings::format_date(latest.timestamp),
+    ANSI_RESET,
+    trend.best.duration,
+    ANSI_ITALIC,
+    trend.best.commit,
+    ANSI_RESET
+  );
+
+  if slowdown > args.threshold {
+    println!(
+      "  {}{:.1}% slower than best{}",
+      ANSI_RED, slowdown, ANSI_RESET
+    );
+  } else {
+    println!();
+  }
+}
+
+fn main() {
+  let args = match parse_args() {
+    Ok(args) => args,
+    Err(e) => {
+      eprintln!("Failed to process arguments: {}", e);
+      process::exit(1);
+    }
+  };
+
+  let history = timings::load_history();
+  let profile = match args
+    .profile
+    .clone()
+    .or_else(|| history.last().map(|e| e.profile.clone()))
+  {
+    Some(profile) => profile,
+    None => {
+      println!("No timings recorded yet. Run `cargo all` to record some.");
+      return;
+    }
+  };
+
+  let trends = timings::trends(&history, &profile);
+  if trends.is_empty() {
+    println!("No timings recorded for the {} profile yet.", profile);
+    return;
+  }
+
+  println!(
+    "{}Performance history ({} profile, last {} runs){}",
+    ANSI_BOLD, profile, args.runs, ANSI_RESET
+  );
+  trends.iter().for_each(|trend| print_trend(trend, &args));
+
+  let regressions = trends
+    .iter()
+    .filter(|trend| trend.slowdown() > args.threshold)
+    .count();
+  println!("---");
+  if regressions == 0 {
+    println!(
+      "🎄 No part is more than {}% slower than its best run.",
+      args.threshold
+    );
+  } else {
+    println!(
+      "🎄 {}{} part(s) are more than {}% slower than their best run.{}",
+      ANSI_RED, regressions, args.threshold, ANSI_RESET
+    );
+  }
+}
@@ -2,20 +2,80 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::{aoc_client, markdown, DEFAULT_YEAR};
+use std::env;
+use std::fs::File;
+use std::io::{self, IsTerminal, Write};
+use std::process::{self, Command, Stdio};
+
+/// Used if the width of the terminal is unknown.
+const DEFAULT_WIDTH: usize = 80;
 
 struct Args {
   day: u8,
   year: Option<u16>,
+  /// Shows the downloaded puzzle without going online.
+  offline: bool,
+  /// Prints the markdown as-is instead of rendering it.
+  raw: bool,
+  width: Option<usize>,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
   let mut args = pico_args::Arguments::from_env();
-  Ok(Args {
+  let parsed = Args {
     day: args.free_from_str()?,
     year: args.opt_value_from_str(["-y", "--year"])?,
-  })
+    offline: args.contains("--offline"),
+    raw: args.contains("--raw"),
+    width: args.opt_value_from_str(["-w", "--width"])?,
+  };
+  advent_of_code::finish_args(args)?;
+  Ok(parsed)
+}
+
+/// `COLUMNS` if the shell exports it, otherwise asks the terminal.
+fn terminal_width() -> Option<usize> {
+  if let Some(columns) = env::var("COLUMNS").ok().and_then(|c| c.parse().ok()) {
+    return Some(columns);
+  }
+  let tty = File::open("/dev/tty").ok()?;
+  let output = Command::new("stty").arg("size").stdin(tty).output().ok()?;
+  String::from_utf8_lossy(&output.stdout)
+    .split_whitespace()
+    .nth(1)?
+    .parse()
+    .ok()
+}
+
+/// Shows `text` in `$PAGER`, `less` by default, if the output is a terminal.
+fn page(text: &str) {
+  if !io::stdout().is_terminal() {
+    print!("{}", text);
+    return;
+  }
+
+  // -F quits if the text fits on the screen, -R shows colors, -X keeps the text on the screen.
+  let pager = env::var("PAGER")
+    .ok()
+    .filter(|pager| !pager.trim().is_empty())
+    .unwrap_or_else(|| "less -FRX".to_string());
+  let mut command = pager.split_whitespace();
+  let child = Command::new(command.next().unwrap_or_default())
+    .args(command)
+    .stdin(Stdio::piped())
+    .spawn();
+
+  match child {
+    Ok(mut child) => {
+      if let Some(mut stdin) = child.stdin.take() {
+        // fails if the pager is closed before reading everything, which is fine.
+        let _ = stdin.write_all(text.as_bytes());
+      }
+      let _ = child.wait();
+    }
+    Err(_) => print!("{}", text),
+  }
 }
 
 fn main() {
@@ -27,20 +87,40 @@ fn main() {
     }
   };
 
-  if aoc_cli::check().is_err() {
-    eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-    process::exit(1);
-  }
-
-  match aoc_cli::read(args.day, args.year) {
-    Ok(cmd_output) => {
-      if !cmd_output.status.success() {
-        process::exit(1);
-      }
-    }
+  let puzzle = match aoc_client::read(args.day, args.year, args.offline) {
+    Ok(puzzle) => puzzle,
     Err(e) => {
-      eprintln!("failed to spawn aoc-cli: {}", e);
-      process::exit(1);
+      // shows what is on disk, e.g. part one only, if the website can't be reached.
+      let local = (!args.offline)
+        .then(|| aoc_client::read(args.day, args.year, true).ok())
+        .flatten();
+      match local {
+        Some(puzzle) => {
+          eprintln!(
+            "Failed to download day {}, showing the downloaded description: {}",
+            args.day, e
+          );
+          puzzle
+        }
+        None => {
+          eprintln!("Failed to read day {}: {}", args.day, e);
+          process::exit(1);
+        }
+      }
     }
+  };
+
+  if args.raw {
+    print!("{}", puzzle);
+    return;
   }
+
+  let width = args.width.or_else(terminal_width).unwrap_or(DEFAULT_WIDTH);
+  let url = format!(
+    "{}/{}/day/{}",
+    aoc_client::DEFAULT_BASE_URL,
+    args.year.unwrap_or(DEFAULT_YEAR),
+    args.day
+  );
+  page(&markdown::render(&puzzle, width, &url));
 }
@@ -2,9 +2,11 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::DEFAULT_YEAR;
 use std::{
-  fs::{File, OpenOptions},
+  fs::{self, File, OpenOptions},
   io::Write,
+  path::Path,
   process,
 };
 
@@ -16,46 +18,49 @@ pub fn part_two(input: &str) -> Option<u32> {
   None
 }
 
-fn main() {
-  let input = &advent_of_code::read_file("inputs", DAY);
-  advent_of_code::solve!(1, part_one, input);
-  advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::main!(DATE);
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_part_one() {
-    let input = advent_of_code::read_file("examples", DAY);
-    assert_eq!(part_one(&input), None);
-  }
-
-  #[test]
-  fn test_part_two() {
-    let input = advent_of_code::read_file("examples", DAY);
-    assert_eq!(part_two(&input), None);
-  }
+  advent_of_code::aoc_tests!(DATE, part_one => None, part_two => None);
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+struct Args {
+  day: u8,
+  year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
   let mut args = pico_args::Arguments::from_env();
-  args.free_from_str()
+  let parsed = Args {
+    day: args.free_from_str()?,
+    year: args.opt_value_from_str(["-y", "--year"])?,
+  };
+  advent_of_code::finish_args(args)?;
+  Ok(parsed)
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
   OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-  OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)?;
+  }
+  OpenOptions::new()
+    .write(true)
+    .create(true)
+    .truncate(false)
+    .open(path)
 }
 
 fn main() {
-  let day = match parse_args() {
-    Ok(day) => day,
+  let (day, year) = match parse_args() {
+    Ok(args) => (args.day, args.year.unwrap_or(DEFAULT_YEAR)),
     Err(_) => {
       eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
       process::exit(1);
@@ -63,10 +68,21 @@ fn main() {
   };
 
   let day_padded = format!("{:02}", day);
+  // other years are prefixed to tell their binaries apart, e.g. `cargo solve 2021-07`.
+  let (bin, date) = match year {
+    DEFAULT_YEAR => (day_padded.clone(), day.to_string()),
+    year => (
+      format!("{}-{}", year, day_padded),
+      format!("{}, {}", year, day),
+    ),
+  };
 
-  let input_path = format!("src/inputs/{}.txt", day_padded);
-  let example_path = format!("src/examples/{}.txt", day_padded);
-  let module_path = format!("src/bin/{}.rs", day_padded);
+  let dir = advent_of_code::year_dir(year);
+  let input_path = dir.join("inputs").join(format!("{}.txt", day_padded));
+  let example_path = dir.join("examples").join(format!("{}.txt", day_padded));
+  let module_path = advent_of_code::year_dir(DEFAULT_YEAR)
+    .join("bin")
+    .join(format!("{}.rs", bin));
 
   let mut file = match safe_create_file(&module_path) {
     Ok(file) => file,
@@ -76,9 +92,9 @@ fn main() {
     }
   };
 
-  match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+  match file.write_all(MODULE_TEMPLATE.replace("DATE", &date).as_bytes()) {
     Ok(_) => {
-      println!("Created module file \"{}\"", &module_path);
+      println!("Created module file \"{}\"", module_path.display());
     }
     Err(e) => {
       eprintln!("Failed to write module contents: {}", e);
@@ -88,7 +104,7 @@ fn main() {
 
   match create_file(&input_path) {
     Ok(_) => {
-      println!("Created empty input file \"{}\"", &input_path);
+      println!("Created empty input file \"{}\"", input_path.display());
     }
     Err(e) => {
       eprintln!("Failed to create input file: {}", e);
@@ -98,7 +114,7 @@ fn main() {
 
   match create_file(&example_path) {
     Ok(_) => {
-      println!("Created empty example file \"{}\"", &example_path);
+      println!("Created empty example file \"{}\"", example_path.display());
     }
     Err(e) => {
       eprintln!("Failed to create example file: {}", e);
@@ -107,8 +123,9 @@ fn main() {
   }
 
   println!("---");
+  println!("🎄 Type `cargo solve {}` to run your solution.", &bin);
   println!(
-    "🎄 Type `cargo solve {}` to run your solution.",
-    &day_padded
+    "🎄 Register day {} of {} in \"src/days.rs\" to include it in `cargo all`.",
+    &day_padded, year
   );
 }
@@ -0,0 +1,115 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc_client::{Client, Outcome};
+use advent_of_code::submissions::{self, Entry};
+use advent_of_code::{
+  days, read_answer, read_year_file, year_dir, ANSI_GREEN, ANSI_RED, ANSI_RESET, DEFAULT_YEAR,
+};
+use std::fs;
+use std::process;
+
+struct Args {
+  day: u8,
+  part: u8,
+  /// Submitted as-is if given, otherwise the day's solver is run for the part.
+  answer: Option<String>,
+  year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+  let mut args = pico_args::Arguments::from_env();
+  let parsed = Args {
+    year: args.opt_value_from_str(["-y", "--year"])?,
+    day: args.free_from_str()?,
+    part: args.free_from_str()?,
+    answer: args.opt_free_from_str()?,
+  };
+  advent_of_code::finish_args(args)?;
+  Ok(parsed)
+}
+
+fn fail(message: impl std::fmt::Display) -> ! {
+  eprintln!("{}", message);
+  process::exit(1);
+}
+
+/// Runs the registered solver of the part against the puzzle input.
+fn solve(year: u16, day: u8, part: u8) -> String {
+  let Some(solver) = days::get(year, day) else {
+    fail(format!(
+      "Day {} is not registered in src/days.rs. Pass the answer to submit it: `cargo submit {} {} <answer>`.",
+      day, day, part
+    ));
+  };
+  let input = read_year_file(year, "inputs", day).unwrap_or_else(|e| fail(e));
+
+  let result = solver.solve(&input, &[part], None).remove(0);
+  print!("{}", result);
+
+  match result.answer {
+    Some(answer) if answer.contains('\n') => fail(
+      "The answer spans several lines and can't be submitted as-is. Pass the answer you read from it instead.",
+    ),
+    Some(answer) => answer,
+    None => fail("The part has no answer to submit."),
+  }
+}
+
+fn main() {
+  let args = match parse_args() {
+    Ok(args) => args,
+    Err(e) => {
+      eprintln!("Failed to process arguments: {}", e);
+      process::exit(1);
+    }
+  };
+  if !matches!(args.part, 1 | 2) {
+    fail(format!("Part must be 1 or 2, got {}.", args.part));
+  }
+
+  let year = args.year.unwrap_or(DEFAULT_YEAR);
+  let (day, part) = (args.day, args.part);
+  let answer = match args.answer {
+    Some(answer) => answer.trim().to_string(),
+    None => solve(year, day, part),
+  };
+
+  if let Some(accepted) = read_answer(year, day, part) {
+    println!(
+      "🎄 Part {} was already solved with {}, not submitting {}.",
+      part, accepted, answer
+    );
+    return;
+  }
+  if let Some(reason) = submissions::known_wrong(&submissions::load(year), day, part, &answer) {
+    fail(format!(
+      "{}Not submitting: {}{}",
+      ANSI_RED, reason, ANSI_RESET
+    ));
+  }
+
+  let client = Client::from_env().unwrap_or_else(|e| fail(format!("Failed to submit: {}", e)));
+  let outcome = client
+    .submit(year, day, part, &answer)
+    .unwrap_or_else(|e| fail(format!("Failed to submit: {}", e)));
+
+  if let Err(e) = submissions::record(year, &Entry::now(day, part, &answer, outcome.clone())) {
+    eprintln!("Failed to record the submission: {}", e);
+  }
+
+  if outcome != Outcome::Correct {
+    fail(format!("{}🎄 {}{}", ANSI_RED, outcome, ANSI_RESET));
+  }
+  println!("{}🎄 {}{}", ANSI_GREEN, outcome, ANSI_RESET);
+
+  // the accepted answer is what `--check` and the input tests compare against.
+  let path = year_dir(year)
+    .join("answers")
+    .join(format!("{:02}-{}.txt", day, part));
+  match fs::write(&path, format!("{}\n", answer)) {
+    Ok(_) => println!("🎄 Saved the answer to \"{}\".", path.display()),
+    Err(e) => eprintln!("Failed to save the answer to \"{}\": {}", path.display(), e),
+  }
+}
@@ -0,0 +1,134 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::watch::{self, Answers};
+use advent_of_code::{ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, DEFAULT_YEAR};
+use std::path::PathBuf;
+use std::process::{self, Command, Output};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+struct Args {
+  day: u8,
+  year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+  let mut args = pico_args::Arguments::from_env();
+  let parsed = Args {
+    day: args.free_from_str()?,
+    year: args.opt_value_from_str(["-y", "--year"])?,
+  };
+  advent_of_code::finish_args(args)?;
+  Ok(parsed)
+}
+
+fn cargo(args: &[&str]) -> Output {
+  match Command::new(env!("CARGO")).args(args).output() {
+    Ok(output) => output,
+    Err(e) => {
+      eprintln!("Failed to run cargo: {}", e);
+      process::exit(1);
+    }
+  }
+}
+
+/// Runs the tests and the solution of `bin`, returns the answers if the solution ran.
+fn run(bin: &str, previous: Option<&Answers>) -> Option<Answers> {
+  let tests = cargo(&["test", "--quiet", "--bin", bin]);
+  let stdout = String::from_utf8_lossy(&tests.stdout);
+  let summary = stdout.lines().find(|line| line.starts_with("test result:"));
+
+  match summary {
+    // without a summary, the tests did not compile. The solution won't either.
+    None => {
+      eprint!("{}", String::from_utf8_lossy(&tests.stderr));
+      return None;
+    }
+    Some(summary) if tests.status.success() => println!(
+      "{}✔ Tests passed {}({}){}",
+      ANSI_GREEN,
+      ANSI_ITALIC,
+      summary.trim_start_matches("test result: "),
+      ANSI_RESET
+    ),
+    Some(_) => println!("{}{}✖ Tests failed{}", stdout, ANSI_RED, ANSI_RESET),
+  }
+
+  let solution = cargo(&["run", "--quiet", "--bin", bin]);
+  let stdout = String::from_utf8_lossy(&solution.stdout);
+  print!("{}", stdout);
+  eprint!("{}", String::from_utf8_lossy(&solution.stderr));
+  if !solution.status.success() {
+    return None;
+  }
+
+  let answers = watch::parse_answers(&stdout);
+  if let Some(previous) = previous {
+    println!("🎄 {}Since the previous run{} 🎄", ANSI_BOLD, ANSI_RESET);
+    watch::diff(previous, &answers)
+      .iter()
+      .for_each(|line| println!("{}", line));
+  }
+  Some(answers)
+}
+
+fn main() {
+  let args = match parse_args() {
+    Ok(args) => args,
+    Err(e) => {
+      eprintln!("Failed to process arguments: {}", e);
+      process::exit(1);
+    }
+  };
+
+  let year = args.year.unwrap_or(DEFAULT_YEAR);
+  let bin = watch::bin_name(year, args.day);
+  let files = watch::watched_files(year, args.day);
+  if !files[0].exists() {
+    eprintln!(
+      "\"{}\" does not exist. Create it with `cargo scaffold {}`.",
+      files[0].display(),
+      args.day
+    );
+    process::exit(1);
+  }
+
+  println!(
+    "Watching {}. Press Ctrl+C to stop.",
+    files
+      .iter()
+      .map(|file| {
+        let file = file
+          .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+          .unwrap_or(file);
+        file.display().to_string()
+      })
+      .collect::<Vec<_>>()
+      .join(", ")
+  );
+
+  let mut previous: Option<Answers> = None;
+  loop {
+    let last_run = state(year, args.day);
+    println!("---");
+    if let Some(answers) = run(&bin, previous.as_ref()) {
+      previous = Some(answers);
+    }
+
+    while last_run == state(year, args.day) {
+      thread::sleep(POLL_INTERVAL);
+    }
+  }
+}
+
+/// The watched files, including new named examples, with their modification times.
+fn state(year: u16, day: u8) -> (Vec<PathBuf>, Vec<Option<SystemTime>>) {
+  let files = watch::watched_files(year, day);
+  let times = watch::snapshot(&files);
+  (files, times)
+}
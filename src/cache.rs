@@ -0,0 +1,194 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Remembers when inputs and puzzles were downloaded, in `src/downloads.txt`, so that `cargo download`
+ * and `cargo read` don't fetch files again that are present and unchanged.
+ */
+use crate::year_dir;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+  Input,
+  Puzzle,
+}
+
+impl Kind {
+  fn name(self) -> &'static str {
+    match self {
+      Kind::Input => "input",
+      Kind::Puzzle => "puzzle",
+    }
+  }
+}
+
+/// One downloaded file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+  pub day: u8,
+  pub kind: Kind,
+  /// Seconds since the unix epoch.
+  pub timestamp: u64,
+  /// `checksum` of the downloaded contents.
+  pub checksum: String,
+}
+
+/// The downloads of one year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+  path: PathBuf,
+  entries: Vec<Entry>,
+}
+
+impl Cache {
+  pub fn load(year: u16) -> Cache {
+    let path = year_dir(year).join("downloads.txt");
+    let entries = fs::read_to_string(&path)
+      .map(|contents| parse(&contents))
+      .unwrap_or_default();
+    Cache { path, entries }
+  }
+
+  /// Where the cache is stored.
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// The download of `path`, if it was recorded and the file still has the downloaded contents.
+  pub fn unchanged(&self, day: u8, kind: Kind, path: &Path) -> Option<&Entry> {
+    let entry = self
+      .entries
+      .iter()
+      .rev()
+      .find(|e| e.day == day && e.kind == kind)?;
+    let contents = fs::read_to_string(path).ok()?;
+    (checksum(&contents) == entry.checksum).then_some(entry)
+  }
+
+  /// Records a download of `contents` and writes the cache to disk.
+  pub fn record(&mut self, day: u8, kind: Kind, contents: &str) -> std::io::Result<()> {
+    self.entries.retain(|e| e.day != day || e.kind != kind);
+    self.entries.push(Entry {
+      day,
+      kind,
+      timestamp: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs()),
+      checksum: checksum(contents),
+    });
+    self.entries.sort_by_key(|e| (e.day, e.kind.name()));
+
+    if let Some(dir) = self.path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+    fs::write(&self.path, serialize(&self.entries))
+  }
+}
+
+/// 64-bit FNV-1a hash of `contents` as hex. Detects changed files, not tampering.
+pub fn checksum(contents: &str) -> String {
+  let hash = contents.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+    (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+  });
+  format!("{:016x}", hash)
+}
+
+fn parse(contents: &str) -> Vec<Entry> {
+  contents
+    .lines()
+    .filter_map(|line| {
+      let fields = line.split_whitespace().collect::<Vec<_>>();
+      match fields[..] {
+        [day, kind, timestamp, checksum] => Some(Entry {
+          day: day.parse().ok()?,
+          kind: match kind {
+            "input" => Kind::Input,
+            "puzzle" => Kind::Puzzle,
+            _ => return None,
+          },
+          timestamp: timestamp.parse().ok()?,
+          checksum: checksum.to_string(),
+        }),
+        _ => None,
+      }
+    })
+    .collect()
+}
+
+fn serialize(entries: &[Entry]) -> String {
+  entries
+    .iter()
+    .map(|e| {
+      format!(
+        "{} {} {} {}\n",
+        e.day,
+        e.kind.name(),
+        e.timestamp,
+        e.checksum
+      )
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_checksum() {
+    assert_eq!(checksum(""), "cbf29ce484222325");
+    assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+    assert_ne!(checksum("1000\n2000\n"), checksum("1000\n2001\n"));
+  }
+
+  #[test]
+  fn test_parse_serialize() {
+    let entries = vec![
+      Entry {
+        day: 1,
+        kind: Kind::Input,
+        timestamp: 1670000000,
+        checksum: checksum("1000\n"),
+      },
+      Entry {
+        day: 1,
+        kind: Kind::Puzzle,
+        timestamp: 1670000001,
+        checksum: checksum("## --- Day 1 ---"),
+      },
+    ];
+    let contents = serialize(&entries);
+    assert!(contents.starts_with("1 input 1670000000 "));
+    assert_eq!(parse(&contents), entries);
+    assert_eq!(parse("1 answer 1670000000 abc\n"), vec![]);
+  }
+
+  #[test]
+  fn test_unchanged() {
+    let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("01.txt");
+    fs::write(&input, "1000\n").unwrap();
+
+    let mut cache = Cache {
+      path: dir.join("downloads.txt"),
+      entries: vec![],
+    };
+    assert_eq!(cache.unchanged(1, Kind::Input, &input), None);
+
+    cache.record(1, Kind::Input, "1000\n").unwrap();
+    assert!(cache.unchanged(1, Kind::Input, &input).is_some());
+    assert_eq!(cache.unchanged(1, Kind::Puzzle, &input), None);
+    assert_eq!(
+      parse(&fs::read_to_string(dir.join("downloads.txt")).unwrap()),
+      cache.entries
+    );
+
+    fs::write(&input, "edited\n").unwrap();
+    assert_eq!(cache.unchanged(1, Kind::Input, &input), None);
+
+    fs::remove_dir_all(dir).unwrap();
+  }
+}
@@ -0,0 +1,141 @@
+/*
+ * This file contains template code.
+ * Register a new day by adding its file to the `modules!` and its module to the `days!` invocation
+ * below. The modules are the same files that `cargo solve <day>` builds from `src/bin/`.
+ */
+use crate::{Bench, SolveResult};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Solves the given parts in order and passes each result to the callback once it is done, so the
+/// runner can give up on a slow part. Days implementing `Solution` parse the input once.
+pub type SolveParts = fn(&str, &[u8], Option<Bench>, &mut dyn FnMut(SolveResult));
+
+/// Type-erased entry point into a single day, used by the all-days runner.
+pub struct Day {
+  pub day: u8,
+  pub solve_parts: SolveParts,
+}
+
+impl Day {
+  /// A part that panics, e.g. on malformed input, is reported as unsolved and the remaining parts
+  /// run again without it, so one day can't end the runner. The panic message is printed to stderr.
+  pub fn solve(&self, input: &str, parts: &[u8], bench: Option<Bench>) -> Vec<SolveResult> {
+    let mut results = vec![];
+    while results.len() < parts.len() {
+      let remaining = &parts[results.len()..];
+      let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        (self.solve_parts)(input, remaining, bench, &mut |result| results.push(result))
+      }));
+      if solved.is_err() {
+        results.push(SolveResult::unsolved(self.day, parts[results.len()]));
+      }
+    }
+    results
+  }
+}
+
+/// Declares the modules of days from their files in `src/bin/`. Their tests already run as part of
+/// the binaries, so the tests of the library leave the days out instead of running them twice.
+macro_rules! modules {
+  ($($path:literal => $module:ident),* $(,)?) => {
+    $(
+      #[cfg(not(test))]
+      #[path = $path]
+      pub mod $module;
+    )*
+  };
+}
+
+/// Days with free functions are registered by their module, days implementing `Solution` by their
+/// type, e.g. `12 => day12::Day12`, which times their parsing separately from the parts.
+macro_rules! days {
+  ($name:ident: $($day:literal => $module:ident $(:: $solution:ident)?),* $(,)?) => {
+    #[cfg(not(test))]
+    pub const $name: &[Day] = &[
+      $(
+        day!($day, $module $(:: $solution)?),
+      )*
+    ];
+    #[cfg(test)]
+    pub const $name: &[Day] = &[];
+  };
+}
+
+#[cfg_attr(test, allow(unused_macros))]
+macro_rules! day {
+  ($day:literal, $module:ident) => {
+    Day {
+      day: $day,
+      solve_parts: |input, parts, bench, report| {
+        for &part in parts {
+          report(match part {
+            1 => crate::solve!($day, 1, $module::part_one, input, bench),
+            _ => crate::solve!($day, 2, $module::part_two, input, bench),
+          });
+        }
+      },
+    }
+  };
+  ($day:literal, $module:ident :: $solution:ident) => {
+    Day {
+      day: $day,
+      solve_parts: crate::solution::solve_parts::<$module::$solution>,
+    }
+  };
+}
+
+modules! {
+  "bin/01.rs" => day01,
+  "bin/02.rs" => day02,
+  "bin/03.rs" => day03,
+  "bin/04.rs" => day04,
+  "bin/05.rs" => day05,
+  "bin/06.rs" => day06,
+  "bin/07.rs" => day07,
+  "bin/08.rs" => day08,
+  "bin/09.rs" => day09,
+  "bin/10.rs" => day10,
+  "bin/11.rs" => day11,
+  "bin/12.rs" => day12,
+  "bin/13.rs" => day13,
+  "bin/14.rs" => day14,
+  "bin/18.rs" => day18,
+  "bin/19.rs" => day19,
+  "bin/20.rs" => day20,
+  "bin/21.rs" => day21,
+}
+
+days! {
+  DAYS:
+  1 => day01,
+  2 => day02,
+  3 => day03,
+  4 => day04,
+  5 => day05,
+  6 => day06,
+  7 => day07,
+  8 => day08,
+  9 => day09,
+  10 => day10,
+  11 => day11,
+  12 => day12::Day12,
+  13 => day13,
+  14 => day14,
+  18 => day18::Day18,
+  19 => day19::Day19,
+  20 => day20,
+  21 => day21::Day21,
+}
+
+/// The solved days of every year. Days of other years are declared like the ones above, e.g.
+/// `modules! { "bin/2021-01.rs" => y2021_day01 }` and `days! { DAYS_2021: 1 => y2021_day01 }`.
+pub const YEARS: &[(u16, &[Day])] = &[(crate::DEFAULT_YEAR, DAYS)];
+
+pub fn get(year: u16, day: u8) -> Option<&'static Day> {
+  YEARS
+    .iter()
+    .find(|(y, _)| *y == year)?
+    .1
+    .iter()
+    .find(|d| d.day == day)
+}
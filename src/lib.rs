@@ -4,229 +4,1042 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::error::Error;
+use std::fmt::Display;
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
 
+use memory::{MemoryStats, Tracker};
+
+// lets the day modules refer to `advent_of_code::` both as binaries and as part of this crate.
+extern crate self as advent_of_code;
+
+include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+pub mod aoc_client;
+pub mod cache;
+pub mod days;
 pub mod helpers;
+pub mod markdown;
+pub mod memory;
+pub mod report;
+pub mod selection;
+pub mod solution;
+pub mod submissions;
+pub mod timings;
+pub mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_CYAN: &str = "\x1b[36m";
+pub const ANSI_UNDERLINE: &str = "\x1b[4m";
+
+/// The year whose files live directly in `src/`.
+/// Other years keep theirs in `src/y<year>/` and their solutions in `src/bin/<year>-<day>.rs`.
+pub const DEFAULT_YEAR: u16 = 2022;
 
+/// Times a part, e.g. `solve!(day, 1, part_one, input, bench)`. With `parse => part_one`, the
+/// input is parsed first and the parse time is reported separately from the part. `parse` returns
+/// a `Result<_, AocError>`, its errors are reported like the errors of parts.
 #[macro_export]
 macro_rules! solve {
-  ($part:expr, $solver:ident, $input:expr) => {{
-    use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-    use std::fmt::Display;
-    use std::time::Instant;
-
-    fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-      let timer = Instant::now();
-      let result = func(input);
-      let elapsed = timer.elapsed();
-      match result {
-        Some(result) => {
-          println!(
-            "{} {}(elapsed: {:.2?}){}",
-            result, ANSI_ITALIC, elapsed, ANSI_RESET
-          );
+  ($day:expr, $part:expr, $parse:path => $solver:path, $input:expr, $bench:expr) => {{
+    let timer = std::time::Instant::now();
+    let parsed = $parse($input);
+    let parse = timer.elapsed();
+    match parsed {
+      Ok(parsed) => advent_of_code::solve!($day, $part, $solver, &parsed, $bench),
+      Err(error) => advent_of_code::SolveResult::failed($day, $part, error),
+    }
+    .with_parse(parse)
+  }};
+  ($day:expr, $part:expr, $solver:path, $input:expr) => {
+    advent_of_code::solve!($day, $part, $solver, $input, None)
+  };
+  ($day:expr, $part:expr, $solver:path, $input:expr, $bench:expr) => {
+    match $bench {
+      Some(bench) => advent_of_code::SolveResult::bench($day, $part, $solver, $input, bench),
+      None => advent_of_code::SolveResult::measure($day, $part, $solver, $input),
+    }
+  };
+}
+
+/// Generates the `main` function of a day's binary, e.g. `main!(1)` or `main!(2021, 1)`.
+/// The day modules are also compiled into this library, where `main` goes unused.
+#[macro_export]
+macro_rules! main {
+  ($day:expr) => {
+    advent_of_code::main!(advent_of_code::DEFAULT_YEAR, $day);
+  };
+  ($year:expr, $day:expr) => {
+    #[allow(dead_code)]
+    fn main() {
+      advent_of_code::run($year, $day, part_one, part_two);
+    }
+  };
+}
+
+/// Generates one test per example and part, named `<part>::<example>`, e.g.
+/// `example_tests! { day: 9, part_one: { a => 13 }, part_two: { a => 1, b => 36 } }`.
+/// Examples are read from `src/examples/<day>-<example>.txt`. Other years start with `year: <year>,`.
+#[macro_export]
+macro_rules! example_tests {
+  (day: $day:expr, $($rest:tt)*) => {
+    advent_of_code::example_tests!(year: advent_of_code::DEFAULT_YEAR, day: $day, $($rest)*);
+  };
+  (year: $year:expr, day: $day:expr, $($part:ident: { $($example:ident => $expected:expr),* $(,)? }),* $(,)?) => {
+    $(
+      mod $part {
+        $(
+          #[test]
+          fn $example() {
+            let input =
+              advent_of_code::read_year_example($year, $day, stringify!($example)).unwrap();
+            assert_eq!(advent_of_code::unwrap_answer(super::$part(&input)), Some($expected));
+          }
+        )*
+      }
+    )*
+  };
+}
+
+/// Generates the tests of a day inside its `tests` module:
+/// - `test_part_one` and `test_part_two` compare the answers for `src/examples/<day>.txt` to the
+///   expected ones, e.g. `aoc_tests!(1, part_one => 24000, part_two => None)` for an unsolved part two.
+/// - `test_part_one_input` and `test_part_two_input` compare the answers for the puzzle input to the
+///   stored answers in `src/answers/`, see `check_input`. Use `aoc_tests!(day)` to only generate these.
+///   A part is ignored if its input or stored answer is missing, see `build.rs`.
+///
+/// Days of other years start with the year, e.g. `aoc_tests!(2021, 1, part_one => 7, part_two => 5)`.
+/// The year and day have to be number literals, so that `build.rs` can tell which inputs exist.
+#[macro_export]
+macro_rules! aoc_tests {
+  (@examples $year:expr, $day:tt, $one:expr, $two:expr) => {
+    #[test]
+    fn test_part_one() {
+      let input = advent_of_code::read_year_file($year, "examples", $day).unwrap();
+      let expected: Option<_> = $one.into();
+      assert_eq!(advent_of_code::unwrap_answer(part_one(&input)), expected);
+    }
+
+    #[test]
+    fn test_part_two() {
+      let input = advent_of_code::read_year_file($year, "examples", $day).unwrap();
+      let expected: Option<_> = $two.into();
+      assert_eq!(advent_of_code::unwrap_answer(part_two(&input)), expected);
+    }
+  };
+  // `$key` is the year as `build.rs` knows it, `default` for the default year.
+  (@input $key:tt, $year:expr, $day:tt) => {
+    advent_of_code::__input_test! {
+      $key, $day, 1,
+      #[test]
+      fn test_part_one_input() {
+        advent_of_code::check_input($year, $day, 1, part_one);
+      }
+    }
+
+    advent_of_code::__input_test! {
+      $key, $day, 2,
+      #[test]
+      fn test_part_two_input() {
+        advent_of_code::check_input($year, $day, 2, part_two);
+      }
+    }
+  };
+  ($year:tt, $day:tt, part_one => $one:expr, part_two => $two:expr $(,)?) => {
+    advent_of_code::aoc_tests!(@examples $year, $day, $one, $two);
+    advent_of_code::aoc_tests!($year, $day);
+  };
+  ($day:tt, part_one => $one:expr, part_two => $two:expr $(,)?) => {
+    advent_of_code::aoc_tests!(@examples advent_of_code::DEFAULT_YEAR, $day, $one, $two);
+    advent_of_code::aoc_tests!($day);
+  };
+  ($year:tt, $day:tt $(,)?) => {
+    advent_of_code::aoc_tests!(@input $year, $year, $day);
+  };
+  ($day:tt $(,)?) => {
+    advent_of_code::aoc_tests!(@input default, advent_of_code::DEFAULT_YEAR, $day);
+  };
+}
+
+/// Generates `part_one`, `part_two` and `main` of a day implemented as a `Solution`, so it can be
+/// solved, tested and registered like the days with free functions.
+/// `part_one` and `part_two` are meant for tests and panic if the input can't be parsed.
+#[macro_export]
+macro_rules! solution {
+  ($solution:ty) => {
+    #[allow(dead_code)]
+    pub fn part_one(input: &str) -> <$solution as advent_of_code::solution::Solution>::PartOne {
+      use advent_of_code::solution::Solution;
+      let parsed = <$solution>::parse(input).unwrap_or_else(|e| panic!("{}", e));
+      <$solution>::part_one(&parsed)
+    }
+
+    #[allow(dead_code)]
+    pub fn part_two(input: &str) -> <$solution as advent_of_code::solution::Solution>::PartTwo {
+      use advent_of_code::solution::Solution;
+      let parsed = <$solution>::parse(input).unwrap_or_else(|e| panic!("{}", e));
+      <$solution>::part_two(&parsed)
+    }
+
+    #[allow(dead_code)]
+    fn main() {
+      advent_of_code::solution::run::<$solution>();
+    }
+  };
+}
+
+/// Entry point of `cargo solve <day>`.
+pub fn run<A: Answer, B: Answer>(
+  year: u16,
+  day: u8,
+  part_one: impl Fn(&str) -> A,
+  part_two: impl Fn(&str) -> B,
+) {
+  let args = SolveArgs::from_env();
+  let input = &args.read_input(year, day);
+  let results = [
+    solve!(day, 1, part_one, input, args.bench),
+    solve!(day, 2, part_two, input, args.bench),
+  ];
+  args.report(year, &results);
+}
+
+/// Command line of `cargo solve <day>`.
+pub struct SolveArgs {
+  pub check: bool,
+  pub bench: Option<Bench>,
+  pub source: InputSource,
+}
+
+impl SolveArgs {
+  /// Parses the arguments of the process, exits with an error message if they are invalid.
+  pub fn from_env() -> SolveArgs {
+    let mut args = pico_args::Arguments::from_env();
+    let check = args.contains("--check");
+    let (bench, source) = match Bench::from_args(&mut args)
+      .and_then(|bench| Ok((bench, InputSource::from_args(args)?)))
+    {
+      Ok(parsed) => parsed,
+      Err(e) => {
+        eprintln!("Failed to process arguments: {}", e);
+        process::exit(1);
+      }
+    };
+
+    if check && source != InputSource::Puzzle {
+      eprintln!("--check compares against the stored answers of the puzzle input and can't be combined with --input.");
+      process::exit(1);
+    }
+
+    SolveArgs {
+      check,
+      bench,
+      source,
+    }
+  }
+
+  /// Reads the selected input, exits with an error message if it can't be read.
+  pub fn read_input(&self, year: u16, day: u8) -> String {
+    match self.source.read(year, day) {
+      Ok(input) => input,
+      Err(e) => {
+        eprintln!("{}", e);
+        process::exit(1);
+      }
+    }
+  }
+
+  /// Prints `results`, exits with a non-zero status if a part failed with an error or `--check`
+  /// found an incorrect answer.
+  pub fn report(&self, year: u16, results: &[SolveResult]) {
+    let mut failed = false;
+    for result in results {
+      print!("{}", result);
+      failed |= result.error.is_some();
+      if self.check {
+        let verdict = Verdict::check(year, result);
+        failed |= verdict.is_incorrect();
+        println!("{}", verdict);
+      }
+    }
+
+    if failed {
+      process::exit(1);
+    }
+  }
+}
+
+/// Fails on the first argument that was not parsed, e.g. a misspelled flag, instead of ignoring it.
+/// A `--` is skipped, as cargo aliases like `cargo time -- --bench` pass it on.
+pub fn finish_args(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+  match args.finish().into_iter().find(|arg| arg != "--") {
+    Some(arg) => Err(pico_args::Error::Utf8ArgumentParsingFailed {
+      value: arg.to_string_lossy().into_owned(),
+      cause: "unknown argument".to_string(),
+    }),
+    None => Ok(()),
+  }
+}
+
+/// Where `cargo solve <day>` reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+  /// `src/inputs/<day>.txt`.
+  Puzzle,
+  Path(PathBuf),
+  Stdin,
+}
+
+impl InputSource {
+  /// Parses `--input <path>`, where a path of `-` (or a lone `-` argument) means stdin.
+  /// Fails on the remaining arguments, see `finish_args`, so it has to be parsed last.
+  pub fn from_args(mut args: pico_args::Arguments) -> Result<InputSource, pico_args::Error> {
+    let path: Option<PathBuf> = args.opt_value_from_str("--input")?;
+    let stdin = args.contains("-");
+    finish_args(args)?;
+
+    Ok(match path {
+      Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+      Some(path) => InputSource::Path(path),
+      None if stdin => InputSource::Stdin,
+      None => InputSource::Puzzle,
+    })
+  }
+
+  pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+    match self {
+      InputSource::Puzzle => read_year_file(year, "inputs", day),
+      InputSource::Path(path) => read_path(path),
+      InputSource::Stdin => {
+        let path = PathBuf::from("<stdin>");
+        let mut contents = String::new();
+        match io::stdin().read_to_string(&mut contents) {
+          Ok(_) => non_empty(contents, &path),
+          Err(e) if e.kind() == io::ErrorKind::InvalidData => Err(InputError::NotUtf8 { path }),
+          Err(source) => Err(InputError::Io { path, source }),
+        }
+      }
+    }
+  }
+}
+
+/// The outcome of running one part of a day against an input.
+#[derive(Debug, Clone)]
+pub struct SolveResult {
+  pub day: u8,
+  pub part: u8,
+  /// `None` if the part is not solved yet.
+  pub answer: Option<String>,
+  /// Time spent in the solver itself, excluding formatting of the answer.
+  /// When benchmarking, this is the median sample.
+  pub duration: Duration,
+  /// Sample statistics, present when the part was benchmarked.
+  pub stats: Option<BenchStats>,
+  /// Set if the runner gave up on the part. `duration` then holds the time limit.
+  pub timed_out: bool,
+  /// Heap usage of the part, present when built with the `alloc-stats` feature.
+  pub memory: Option<MemoryStats>,
+  /// Time spent parsing the input before the part, if parsing is timed separately.
+  pub parse: Option<Duration>,
+  /// Set if the part, or parsing its input, returned an error instead of an answer.
+  pub error: Option<AocError>,
+}
+
+impl SolveResult {
+  /// A part that could not be run, e.g. because the day has no solution or input yet.
+  pub fn unsolved(day: u8, part: u8) -> SolveResult {
+    SolveResult {
+      day,
+      part,
+      answer: None,
+      duration: Duration::ZERO,
+      stats: None,
+      timed_out: false,
+      memory: None,
+      parse: None,
+      error: None,
+    }
+  }
+
+  /// A part that returned an error instead of an answer.
+  pub fn failed(day: u8, part: u8, error: AocError) -> SolveResult {
+    SolveResult {
+      error: Some(error),
+      ..SolveResult::unsolved(day, part)
+    }
+  }
+
+  pub fn with_parse(self, parse: Duration) -> SolveResult {
+    SolveResult {
+      parse: Some(parse),
+      ..self
+    }
+  }
+
+  /// A part that did not finish within `limit`.
+  pub fn timed_out(day: u8, part: u8, limit: Duration) -> SolveResult {
+    SolveResult {
+      duration: limit,
+      timed_out: true,
+      ..SolveResult::unsolved(day, part)
+    }
+  }
+
+  pub fn measure<I: ?Sized, A: Answer>(
+    day: u8,
+    part: u8,
+    func: impl FnOnce(&I) -> A,
+    input: &I,
+  ) -> SolveResult {
+    let tracker = Tracker::start();
+    let timer = Instant::now();
+    let answer = func(input);
+    let duration = timer.elapsed();
+    let memory = tracker.finish();
+    let (answer, error) = answer.into_strings();
+
+    SolveResult {
+      day,
+      part,
+      answer,
+      duration,
+      stats: None,
+      timed_out: false,
+      memory,
+      parse: None,
+      error,
+    }
+  }
+
+  /// Runs `func` `bench.warmup` times without measuring, then times `bench.samples` runs.
+  pub fn bench<I: ?Sized, A: Answer>(
+    day: u8,
+    part: u8,
+    func: impl Fn(&I) -> A,
+    input: &I,
+    bench: Bench,
+  ) -> SolveResult {
+    for _ in 0..bench.warmup {
+      func(input);
+    }
+
+    let mut answer = (None, None);
+    let mut memory = None;
+    let samples = (0..bench.samples.max(1))
+      .map(|i| {
+        // heap usage is the same for every sample, only track the first one.
+        let tracker = (i == 0).then(Tracker::start);
+        let timer = Instant::now();
+        let result = func(input);
+        let elapsed = timer.elapsed();
+        answer = result.into_strings();
+        if let Some(tracker) = tracker {
+          memory = tracker.finish();
         }
-        None => {
-          println!("not solved.")
+        elapsed
+      })
+      .collect();
+    let stats = BenchStats::from_samples(samples);
+    let (answer, error) = answer;
+
+    SolveResult {
+      day,
+      part,
+      answer,
+      duration: stats.median,
+      stats: Some(stats),
+      timed_out: false,
+      memory,
+      parse: None,
+      error,
+    }
+  }
+}
+
+impl Display for SolveResult {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET)?;
+    match &self.answer {
+      Some(answer) => {
+        let mut details = vec![match &self.stats {
+          Some(stats) => stats.to_string(),
+          None => format!("elapsed: {:.2?}", self.duration),
+        }];
+        if let Some(parse) = self.parse {
+          details.push(format!("parse: {:.2?}", parse));
         }
+        if let Some(memory) = &self.memory {
+          details.push(memory.to_string());
+        }
+        writeln!(
+          f,
+          "{} {}({}){}",
+          answer,
+          ANSI_ITALIC,
+          details.join(", "),
+          ANSI_RESET
+        )
       }
+      None if self.timed_out => writeln!(f, "timed out after {:.2?}.", self.duration),
+      None => match &self.error {
+        Some(error) => writeln!(f, "{}{}{}", ANSI_RED, error, ANSI_RESET),
+        None => writeln!(f, "not solved."),
+      },
     }
+  }
+}
 
-    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-    print_result($solver, $input);
-  }};
+/// Settings for repeated-sample benchmarking, enabled with `--bench`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+  pub warmup: u32,
+  pub samples: u32,
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-  let cwd = env::current_dir().unwrap();
+impl Bench {
+  pub const DEFAULT_WARMUP: u32 = 3;
+  pub const DEFAULT_SAMPLES: u32 = 25;
 
-  let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+  /// Parses `--bench [--samples <n>] [--warmup <n>]`. Returns `None` without `--bench`.
+  pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Bench>, pico_args::Error> {
+    let enabled = args.contains("--bench");
+    let samples = args.opt_value_from_str("--samples")?;
+    let warmup = args.opt_value_from_str("--warmup")?;
 
-  let f = fs::read_to_string(filepath);
-  f.expect("could not open input file")
+    Ok(enabled.then(|| Bench {
+      warmup: warmup.unwrap_or(Bench::DEFAULT_WARMUP),
+      samples: samples.unwrap_or(Bench::DEFAULT_SAMPLES),
+    }))
+  }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-  val.split(postfix).next().unwrap().parse().unwrap()
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchStats {
+  pub samples: usize,
+  pub min: Duration,
+  pub median: Duration,
+  pub mean: Duration,
+  pub stddev: Duration,
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-  output.lines().fold(0_f64, |acc, l| {
-    if !l.contains("elapsed:") {
-      acc
+impl BenchStats {
+  /// Panics if `samples` is empty.
+  pub fn from_samples(mut samples: Vec<Duration>) -> BenchStats {
+    samples.sort();
+
+    let n = samples.len();
+    let median = if n.is_multiple_of(2) {
+      (samples[n / 2 - 1] + samples[n / 2]) / 2
     } else {
-      let timing = l.split("(elapsed: ").last().unwrap();
-      // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-      // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-      if timing.contains("ns)") {
-        acc // range below rounding precision.
-      } else if timing.contains("µs)") {
-        acc + parse_time(timing, "µs") / 1000_f64
-      } else if timing.contains("ms)") {
-        acc + parse_time(timing, "ms")
-      } else if timing.contains("s)") {
-        acc + parse_time(timing, "s") * 1000_f64
-      } else {
-        acc
-      }
+      samples[n / 2]
+    };
+
+    let secs = samples
+      .iter()
+      .map(Duration::as_secs_f64)
+      .collect::<Vec<_>>();
+    let mean = secs.iter().sum::<f64>() / n as f64;
+    let variance = if n > 1 {
+      secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+    } else {
+      0_f64
+    };
+
+    BenchStats {
+      samples: n,
+      min: samples[0],
+      median,
+      mean: Duration::from_secs_f64(mean),
+      stddev: Duration::from_secs_f64(variance.sqrt()),
     }
-  })
+  }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-  ($a:expr, $b:expr) => {{
-    let (a, b) = (&$a, &$b);
-    assert!(
-      (*a - *b).abs() < 1.0e-6,
-      "{} is not approximately equal to {}",
-      *a,
-      *b
-    );
-  }};
+impl Display for BenchStats {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "min: {:.2?}, median: {:.2?}, mean: {:.2?} ± {:.2?}, {} samples",
+      self.min, self.median, self.mean, self.stddev, self.samples
+    )
+  }
 }
 
-#[cfg(test)]
-mod tests {
-  use super::*;
+/// Outcome of comparing a part's answer with the accepted one stored in `src/answers/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+  /// No accepted answer is stored for this part.
+  Unknown,
+  Correct,
+  Incorrect {
+    expected: String,
+  },
+}
 
-  #[test]
-  fn test_parse_exec_time() {
-    assert_approx_eq!(
-      parse_exec_time(&format!(
-        "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-        ANSI_RESET, ANSI_RESET
-      )),
-      0_f64
-    );
+impl Verdict {
+  pub fn check(year: u16, result: &SolveResult) -> Verdict {
+    Verdict::compare(
+      result.answer.as_deref(),
+      read_answer(year, result.day, result.part).as_deref(),
+    )
+  }
 
-    assert_approx_eq!(
-      parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-      1.455_f64
-    );
+  pub fn compare(answer: Option<&str>, expected: Option<&str>) -> Verdict {
+    match expected {
+      None => Verdict::Unknown,
+      Some(expected) if answer == Some(expected) => Verdict::Correct,
+      Some(expected) => Verdict::Incorrect {
+        expected: expected.to_string(),
+      },
+    }
+  }
 
-    assert_approx_eq!(
-      parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-      1.52_f64
-    );
+  pub fn is_incorrect(&self) -> bool {
+    matches!(self, Verdict::Incorrect { .. })
+  }
+}
 
-    assert_approx_eq!(
-      parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"),
-      10400.50_f64
-    );
+impl Display for Verdict {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Verdict::Unknown => write!(f, "{}no stored answer.{}", ANSI_ITALIC, ANSI_RESET),
+      Verdict::Correct => write!(f, "{}✔ matches stored answer.{}", ANSI_GREEN, ANSI_RESET),
+      Verdict::Incorrect { expected } => write!(
+        f,
+        "{}✘ does not match stored answer: {}{}",
+        ANSI_RED, expected, ANSI_RESET
+      ),
+    }
   }
 }
 
-pub mod aoc_cli {
-  use std::{
-    fmt::Display,
-    fs::create_dir_all,
-    process::{Command, Output, Stdio},
+/// Solves the puzzle input of a day and asserts that the answer matches the stored one.
+/// Passes without checking anything if the input or the stored answer is missing. `aoc_tests!`
+/// marks those tests as ignored, so this only happens with `cargo test -- --ignored`.
+/// Empty inputs, like the one created by `cargo scaffold`, count as missing.
+pub fn check_input<A: Answer>(year: u16, day: u8, part: u8, solver: impl Fn(&str) -> A) {
+  let input = match read_year_file(year, "inputs", day) {
+    Ok(input) => input,
+    Err(InputError::Missing { .. } | InputError::Empty { .. }) => return,
+    Err(e) => panic!("{}", e),
+  };
+  let Some(expected) = read_answer(year, day, part) else {
+    return;
   };
 
-  pub enum AocCliError {
-    CommandNotFound,
-    CommandNotCallable,
-    BadExitStatus(Output),
-    IoError,
-  }
+  assert_eq!(
+    unwrap_answer(solver(&input)).map(|answer| answer.to_string()),
+    Some(expected),
+    "answer of {} day {:02}, part {} does not match the stored answer",
+    year,
+    day,
+    part
+  );
+}
+
+/// Reads the accepted answer for a part from `src/answers/<day>-<part>.txt`.
+/// Trailing line breaks are ignored, so answers spanning multiple lines can be stored as-is.
+pub fn read_answer(year: u16, day: u8, part: u8) -> Option<String> {
+  let filepath = year_dir(year)
+    .join("answers")
+    .join(format!("{:02}-{}.txt", day, part));
 
-  impl Display for AocCliError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-      match self {
-        AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-        AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-        AocCliError::BadExitStatus(_) => {
-          write!(f, "aoc-cli exited with a non-zero status.")
+  fs::read_to_string(filepath)
+    .ok()
+    .map(|answer| answer.trim_end_matches(['\n', '\r']).to_string())
+}
+
+/// Why `read_file` could not load an input.
+#[derive(Debug)]
+pub enum InputError {
+  /// The file does not exist.
+  Missing {
+    path: PathBuf,
+    folder: String,
+    year: u16,
+    day: u8,
+  },
+  NotUtf8 {
+    path: PathBuf,
+  },
+  /// The file exists but contains nothing, usually a download or copy that went wrong.
+  Empty {
+    path: PathBuf,
+  },
+  /// Any other error of the file system, e.g. missing permissions.
+  Io {
+    path: PathBuf,
+    source: io::Error,
+  },
+}
+
+impl Display for InputError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      InputError::Missing {
+        path,
+        folder,
+        year,
+        day,
+      } => {
+        write!(f, "could not find \"{}\".", path.display())?;
+        let year = match *year {
+          DEFAULT_YEAR => String::new(),
+          year => format!(" --year {}", year),
+        };
+        match folder.as_str() {
+          "inputs" => write!(
+            f,
+            " Run `cargo download {:02}{}` to download it.",
+            day, year
+          ),
+          "examples" => write!(f, " Run `cargo scaffold {:02}{}` to create it.", day, year),
+          _ => Ok(()),
         }
-        AocCliError::IoError => write!(f, "could not write output files to file system."),
       }
+      InputError::NotUtf8 { path } => write!(f, "\"{}\" is not valid UTF-8.", path.display()),
+      InputError::Empty { path } => write!(f, "\"{}\" is empty.", path.display()),
+      InputError::Io { path, source } => {
+        write!(f, "could not read \"{}\": {}", path.display(), source)
+      }
+    }
+  }
+}
+
+impl Error for InputError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      InputError::Io { source, .. } => Some(source),
+      _ => None,
+    }
+  }
+}
+
+/// An error of a solution, usually an input it can't parse. Parts return it as `Result<T, AocError>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+  /// Line of the input the error refers to, starting at 1.
+  pub line: Option<usize>,
+  /// Column of `line` the error refers to in characters, starting at 1.
+  pub column: Option<usize>,
+  pub message: String,
+}
+
+impl AocError {
+  pub fn new(message: impl Into<String>) -> AocError {
+    AocError {
+      line: None,
+      column: None,
+      message: message.into(),
     }
   }
 
-  pub fn check() -> Result<(), AocCliError> {
-    Command::new("aoc")
-      .arg("-V")
-      .output()
-      .map_err(|_| AocCliError::CommandNotFound)?;
-    Ok(())
+  /// An error about `span`, a slice of `input` like a token of a line, e.g.
+  /// `AocError::at(input, token, "expected a number")`.
+  pub fn at(input: &str, span: &str, message: impl Into<String>) -> AocError {
+    match offset_in(input, span) {
+      Some(offset) => {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        AocError {
+          line: Some(before.matches('\n').count() + 1),
+          column: Some(before[line_start..].chars().count() + 1),
+          message: message.into(),
+        }
+      }
+      None => AocError::new(message),
+    }
   }
 
-  pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-    // TODO: output local puzzle if present.
-    let args = build_args("read", &[], day, year);
-    call_aoc_cli(&args)
+  /// An error about the line of `input` that contains `span`, e.g.
+  /// `AocError::at_line(input, line, "expected an instruction")`.
+  pub fn at_line(input: &str, span: &str, message: impl Into<String>) -> AocError {
+    AocError {
+      column: None,
+      ..AocError::at(input, span, message)
+    }
   }
+}
 
-  pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-    let input_path = get_input_path(day);
+/// Byte offset of `span` in `input`, `None` if it is not a slice of `input`.
+fn offset_in(input: &str, span: &str) -> Option<usize> {
+  let offset = (span.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+  (offset + span.len() <= input.len() && input.is_char_boundary(offset)).then_some(offset)
+}
 
-    let puzzle_path = get_puzzle_path(day);
-    create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
+impl Display for AocError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match (self.line, self.column) {
+      (Some(line), Some(column)) => write!(
+        f,
+        "parse error at line {}, column {}: {}",
+        line, column, self.message
+      ),
+      (Some(line), None) => write!(f, "parse error at line {}: {}", line, self.message),
+      _ => write!(f, "{}", self.message),
+    }
+  }
+}
 
-    let args = build_args(
-      "download",
-      &[
-        "--overwrite".into(),
-        "--input-file".into(),
-        input_path.to_string(),
-        "--puzzle-file".into(),
-        puzzle_path.to_string(),
-      ],
-      day,
-      year,
-    );
+impl Error for AocError {}
 
-    let output = call_aoc_cli(&args)?;
+/// What a part returns: `Option<T>`, where `None` means the part is not solved yet, or
+/// `Result<T, AocError>` for parts that can fail.
+pub trait Answer {
+  type Value: Display;
 
-    if output.status.success() {
-      println!("---");
-      println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-      println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-      Ok(output)
-    } else {
-      Err(AocCliError::BadExitStatus(output))
+  fn into_result(self) -> Result<Option<Self::Value>, AocError>;
+
+  /// The formatted answer and error, as stored in a `SolveResult`.
+  fn into_strings(self) -> (Option<String>, Option<AocError>)
+  where
+    Self: Sized,
+  {
+    match self.into_result() {
+      Ok(answer) => (answer.map(|a| a.to_string()), None),
+      Err(error) => (None, Some(error)),
     }
   }
+}
+
+impl<T: Display> Answer for Option<T> {
+  type Value = T;
 
-  fn get_input_path(day: u8) -> String {
-    let day_padded = format!("{:02}", day);
-    format!("src/inputs/{}.txt", day_padded)
+  fn into_result(self) -> Result<Option<T>, AocError> {
+    Ok(self)
   }
+}
+
+impl<T: Display> Answer for Result<T, AocError> {
+  type Value = T;
 
-  fn get_puzzle_path(day: u8) -> String {
-    let day_padded = format!("{:02}", day);
-    format!("src/puzzles/{}.md", day_padded)
+  fn into_result(self) -> Result<Option<T>, AocError> {
+    self.map(Some)
   }
+}
+
+/// The answer of a part in tests. Panics with the message of the error if the part failed.
+pub fn unwrap_answer<A: Answer>(answer: A) -> Option<A::Value> {
+  answer.into_result().unwrap_or_else(|e| panic!("{}", e))
+}
 
-  fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-    let mut cmd_args = args.to_vec();
+/// Directory with the inputs, examples, answers and puzzles of `year`, see `DEFAULT_YEAR`.
+/// Paths are relative to the crate root so they work from any directory.
+pub fn year_dir(year: u16) -> PathBuf {
+  let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+  match year {
+    DEFAULT_YEAR => src,
+    year => src.join(format!("y{}", year)),
+  }
+}
 
-    if let Some(year) = year {
-      cmd_args.push("--year".into());
-      cmd_args.push(year.to_string());
+/// Reads `src/<folder>/<day>.txt` of the default year.
+pub fn read_file(folder: &str, day: u8) -> Result<String, InputError> {
+  read_year_file(DEFAULT_YEAR, folder, day)
+}
+
+/// Reads `<folder>/<day>.txt` from the directory of `year`.
+pub fn read_year_file(year: u16, folder: &str, day: u8) -> Result<String, InputError> {
+  let filepath = year_dir(year).join(folder).join(format!("{:02}.txt", day));
+
+  read_path(&filepath).map_err(|e| match e {
+    InputError::Io { path, source } if source.kind() == io::ErrorKind::NotFound => {
+      InputError::Missing {
+        path,
+        folder: folder.to_string(),
+        year,
+        day,
+      }
     }
+    e => e,
+  })
+}
+
+/// Reads one of several examples of a day from `src/examples/<day>-<example>.txt`.
+pub fn read_example(day: u8, example: &str) -> Result<String, InputError> {
+  read_year_example(DEFAULT_YEAR, day, example)
+}
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+/// Like `read_example`, for the examples of `year`.
+pub fn read_year_example(year: u16, day: u8, example: &str) -> Result<String, InputError> {
+  read_path(
+    &year_dir(year)
+      .join("examples")
+      .join(format!("{:02}-{}.txt", day, example)),
+  )
+}
+
+fn read_path(path: &Path) -> Result<String, InputError> {
+  let contents = fs::read_to_string(path).map_err(|source| match source.kind() {
+    io::ErrorKind::InvalidData => InputError::NotUtf8 {
+      path: path.to_path_buf(),
+    },
+    _ => InputError::Io {
+      path: path.to_path_buf(),
+      source,
+    },
+  })?;
+
+  non_empty(contents, path)
+}
 
-    cmd_args
+fn non_empty(contents: String, path: &Path) -> Result<String, InputError> {
+  if contents.is_empty() {
+    return Err(InputError::Empty {
+      path: path.to_path_buf(),
+    });
   }
+  Ok(contents)
+}
 
-  fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-    if cfg!(debug_assertions) {
-      println!("Calling >aoc with: {}", args.join(" "));
-    }
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_solve_result() {
+    let solved = SolveResult::measure(1, 2, |input| Some(input.len()), "abc");
+    assert_eq!((solved.day, solved.part), (1, 2));
+    assert_eq!(solved.answer.as_deref(), Some("3"));
+
+    let unsolved = SolveResult::measure(1, 1, |_| None::<u32>, "abc");
+    assert_eq!(unsolved.answer, None);
+    assert!(unsolved.to_string().ends_with("not solved.\n"));
+
+    let parsed = SolveResult::measure(1, 1, |input: &[u32]| input.first().copied(), &[7][..])
+      .with_parse(Duration::from_micros(20));
+    assert_eq!(parsed.answer.as_deref(), Some("7"));
+    assert!(parsed.to_string().contains(", parse: 20.00µs"));
+
+    let failed = SolveResult::measure(1, 1, |_| Err::<u32, _>(AocError::new("no monkeys")), "");
+    assert_eq!(failed.answer, None);
+    assert_eq!(failed.error, Some(AocError::new("no monkeys")));
+    assert!(failed.to_string().ends_with("no monkeys\x1b[0m\n"));
+
+    let timed_out = SolveResult::timed_out(1, 1, Duration::from_secs(5));
+    assert!(timed_out.to_string().ends_with("timed out after 5.00s.\n"));
+  }
+
+  #[test]
+  fn test_aoc_error() {
+    let input = "noop\naddx 3\naddx x\n";
+    let token = input.lines().nth(2).unwrap().split(' ').nth(1).unwrap();
+
+    let error = AocError::at(input, token, "expected a number");
+    assert_eq!((error.line, error.column), (Some(3), Some(6)));
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 3, column 6: expected a number"
+    );
+
+    let error = AocError::at_line(input, token, "expected `addx <n>`");
+    assert_eq!(
+      error.to_string(),
+      "parse error at line 3: expected `addx <n>`"
+    );
+
+    let error = AocError::at(input, "addx 3", "not part of the input");
+    assert_eq!(error.to_string(), "not part of the input");
+
+    assert_eq!(unwrap_answer(Ok::<_, AocError>(3)), Some(3));
+    assert_eq!(unwrap_answer(None::<u32>), None);
+  }
+
+  #[test]
+  fn test_read_file() {
+    assert!(read_file("examples", 1).is_ok_and(|input| input.starts_with("1000")));
+
+    let missing = read_file("inputs", 26).unwrap_err();
+    assert!(matches!(missing, InputError::Missing { day: 26, .. }));
+    assert!(missing
+      .to_string()
+      .ends_with("Run `cargo download 26` to download it."));
+
+    let missing = read_year_file(2015, "inputs", 1).unwrap_err();
+    assert!(missing.to_string().contains("y2015"));
+    assert!(missing
+      .to_string()
+      .ends_with("Run `cargo download 01 --year 2015` to download it."));
+
+    let dir = env::temp_dir().join(format!("aoc-read-file-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("empty.txt"), "").unwrap();
+    fs::write(dir.join("binary.txt"), [0xff, 0xfe]).unwrap();
+    assert!(matches!(
+      read_path(&dir.join("empty.txt")),
+      Err(InputError::Empty { .. })
+    ));
+    assert!(matches!(
+      read_path(&dir.join("binary.txt")),
+      Err(InputError::NotUtf8 { .. })
+    ));
+    fs::remove_dir_all(dir).unwrap();
+  }
+
+  #[test]
+  fn test_input_source() {
+    let parse = |args: &[&str]| {
+      InputSource::from_args(pico_args::Arguments::from_vec(
+        args.iter().map(Into::into).collect(),
+      ))
+      .map_err(|e| e.to_string())
+    };
+    assert_eq!(parse(&[]), Ok(InputSource::Puzzle));
+    assert_eq!(
+      parse(&["--input", "big.txt"]),
+      Ok(InputSource::Path(PathBuf::from("big.txt")))
+    );
+    assert_eq!(parse(&["--input", "-"]), Ok(InputSource::Stdin));
+    assert_eq!(parse(&["-"]), Ok(InputSource::Stdin));
+    assert_eq!(parse(&["--", "-"]), Ok(InputSource::Stdin));
+    assert_eq!(
+      parse(&["--chekc"]),
+      Err("failed to parse '--chekc': unknown argument".to_string())
+    );
+  }
+
+  #[test]
+  fn test_verdict() {
+    assert_eq!(Verdict::compare(Some("42"), None), Verdict::Unknown);
+    assert_eq!(Verdict::compare(Some("42"), Some("42")), Verdict::Correct);
+    assert_eq!(
+      Verdict::compare(Some("41"), Some("42")),
+      Verdict::Incorrect {
+        expected: "42".to_string()
+      }
+    );
+    assert!(Verdict::compare(None, Some("42")).is_incorrect());
+  }
+
+  #[test]
+  fn test_bench_stats() {
+    let stats = BenchStats::from_samples(
+      [4, 1, 3, 2]
+        .iter()
+        .map(|ms| Duration::from_millis(*ms))
+        .collect(),
+    );
+    assert_eq!(stats.samples, 4);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.mean, Duration::from_micros(2500));
+    // sample standard deviation of 1, 2, 3, 4 is sqrt(5 / 3)
+    assert_eq!(stats.stddev.as_micros(), 1290);
 
-    Command::new("aoc")
-      .args(args)
-      .stdout(Stdio::inherit())
-      .stderr(Stdio::inherit())
-      .output()
-      .map_err(|_| AocCliError::CommandNotCallable)
+    let bench = Bench {
+      warmup: 1,
+      samples: 5,
+    };
+    let result = SolveResult::bench(1, 1, |input| Some(input.len()), "abc", bench);
+    assert_eq!(result.answer.as_deref(), Some("3"));
+    assert_eq!(result.stats.map(|s| s.samples), Some(5));
   }
 }
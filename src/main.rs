@@ -2,47 +2,386 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::days::{self, Day};
+use advent_of_code::memory;
+use advent_of_code::report::{self, Format};
+use advent_of_code::selection::{self, DaySelection};
+use advent_of_code::timings;
+use advent_of_code::{
+  Bench, InputError, SolveResult, Verdict, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, DEFAULT_YEAR,
+};
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
 
-fn main() {
-  let total: f64 = (1..=25)
-    .map(|day| {
-      let day = format!("{:02}", day);
+struct Args {
+  year: u16,
+  bench: Option<Bench>,
+  format: Format,
+  check: bool,
+  /// Number of worker threads, if days should be solved concurrently.
+  jobs: Option<usize>,
+  days: DaySelection,
+  part: Option<u8>,
+  only_solved: bool,
+  /// Skip parts that took longer than this many milliseconds in the last run.
+  skip_slow: Option<u64>,
+  /// Give up on parts that do not finish within this time.
+  timeout: Option<Duration>,
+  /// Write a table of the timings to `README.md`.
+  readme: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+  let mut args = pico_args::Arguments::from_env();
+  let parsed = Args {
+    year: args
+      .opt_value_from_str(["-y", "--year"])?
+      .unwrap_or(DEFAULT_YEAR),
+    bench: Bench::from_args(&mut args)?,
+    format: args.opt_value_from_str("--format")?.unwrap_or(Format::Text),
+    check: args.contains("--check"),
+    jobs: match (
+      args.contains("--parallel"),
+      args.opt_value_from_str("--jobs")?,
+    ) {
+      (_, Some(jobs)) => Some(jobs),
+      (true, None) => Some(thread::available_parallelism().map_or(1, |n| n.get())),
+      (false, None) => None,
+    },
+    days: args
+      .opt_value_from_str("--days")?
+      .unwrap_or_else(DaySelection::all),
+    part: args.opt_value_from_fn("--part", selection::parse_part)?,
+    only_solved: args.contains("--only-solved"),
+    skip_slow: args.opt_value_from_str("--skip-slow")?,
+    timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+    readme: args.contains("--readme"),
+  };
+  advent_of_code::finish_args(args)?;
+  Ok(parsed)
+}
+
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+  let secs = s.parse::<f64>().map_err(|e| e.to_string())?;
+  Duration::try_from_secs_f64(secs).map_err(|e| e.to_string())
+}
 
-      let mut args = vec!["run", "--bin", &day];
-      if cfg!(not(debug_assertions)) {
-        args.push("--release");
+/// The parts of a day selected to run.
+struct Task {
+  day: u8,
+  parts: Vec<u8>,
+  /// Parts left out by `--skip-slow`, with their duration in the last run.
+  skipped: Vec<(u8, Duration)>,
+}
+
+fn plan(args: &Args) -> Vec<Task> {
+  // the performance history only covers the default year.
+  let last_run = match args.skip_slow {
+    Some(_) if args.year == DEFAULT_YEAR => timings::load(),
+    _ => timings::Timings::new(),
+  };
+
+  args
+    .days
+    .0
+    .iter()
+    .map(|&day| {
+      let (parts, skipped) = [1, 2]
+        .into_iter()
+        .filter(|part| args.part.is_none_or(|p| p == *part))
+        .map(|part| (part, last_run.get(&(day, part)).copied()))
+        .partition::<Vec<_>, _>(|(_, last)| match (args.skip_slow, last) {
+          (Some(limit), Some(last)) => last.as_millis() <= u128::from(limit),
+          _ => true,
+        });
+
+      Task {
+        day,
+        parts: parts.into_iter().map(|(part, _)| part).collect(),
+        skipped: skipped
+          .into_iter()
+          .map(|(part, last)| (part, last.unwrap()))
+          .collect(),
       }
+    })
+    .collect()
+}
 
-      let cmd = Command::new("cargo").args(&args).output().unwrap();
+/// Solves the selected parts of a day, or returns `None` if the day has no solution or input.
+/// Inputs that exist but can't be used are reported on stderr.
+fn solve_day(task: &Task, args: &Args) -> Option<Vec<SolveResult>> {
+  let solution = days::get(args.year, task.day)?;
+  let input = match advent_of_code::read_year_file(args.year, "inputs", task.day) {
+    Ok(input) => Arc::new(input),
+    Err(InputError::Missing { .. }) => return None,
+    Err(e) => {
+      eprintln!("{}", e);
+      return None;
+    }
+  };
 
-      println!("----------");
-      println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-      println!("----------");
+  Some(match args.timeout {
+    Some(limit) => solve_parts_with_timeout(solution, &task.parts, &input, args.bench, limit),
+    None => solution.solve(&input, &task.parts, args.bench),
+  })
+}
 
-      let output = String::from_utf8(cmd.stdout).unwrap();
-      let is_empty = output.is_empty();
+/// Runs the day on a separate thread and stops waiting for a part after `limit`. Parsing counts
+/// towards the limit of the first part. The remaining parts then run on a thread of their own.
+/// A part that times out can't be interrupted and keeps running until the runner exits.
+fn solve_parts_with_timeout(
+  solution: &Day,
+  parts: &[u8],
+  input: &Arc<String>,
+  bench: Option<Bench>,
+  limit: Duration,
+) -> Vec<SolveResult> {
+  let mut results = vec![];
 
-      println!(
-        "{}",
-        if is_empty {
-          "Not solved."
-        } else {
-          output.trim()
+  while results.len() < parts.len() {
+    let remaining = parts[results.len()..].to_vec();
+    let (sender, receiver) = mpsc::channel();
+    let (solve_parts, input) = (solution.solve_parts, Arc::clone(input));
+    let to_solve = remaining.clone();
+    thread::spawn(move || {
+      solve_parts(&input, &to_solve, bench, &mut |result| {
+        // the receiver is gone if a part already timed out.
+        let _ = sender.send(result);
+      })
+    });
+
+    for part in remaining {
+      match receiver.recv_timeout(limit) {
+        Ok(result) => results.push(result),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+          results.push(SolveResult::timed_out(solution.day, part, limit));
+          break;
         }
-      );
+        // the part panicked, its message was already printed to stderr.
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+          results.push(SolveResult::unsolved(solution.day, part));
+          break;
+        }
+      }
+    }
+  }
 
-      if is_empty {
-        0_f64
+  results
+}
+
+/// Solves `tasks` on `jobs` worker threads and returns the outcomes in the order of `tasks`.
+fn solve_days_parallel(tasks: &[Task], args: &Args, jobs: usize) -> Vec<Option<Vec<SolveResult>>> {
+  let next = AtomicUsize::new(0);
+
+  let mut outcomes: Vec<(usize, Option<Vec<SolveResult>>)> = thread::scope(|scope| {
+    let workers = (0..jobs.max(1))
+      .map(|_| {
+        scope.spawn(|| {
+          let mut solved = vec![];
+          loop {
+            let i = next.fetch_add(1, Ordering::Relaxed);
+            match tasks.get(i) {
+              Some(task) => solved.push((i, solve_day(task, args))),
+              None => return solved,
+            }
+          }
+        })
+      })
+      .collect::<Vec<_>>();
+
+    workers
+      .into_iter()
+      .flat_map(|worker| worker.join().unwrap())
+      .collect()
+  });
+
+  outcomes.sort_by_key(|(i, _)| *i);
+  outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+fn main() {
+  let args = match parse_args() {
+    Ok(args) => args,
+    Err(e) => {
+      eprintln!("Failed to process arguments: {}", e);
+      process::exit(1);
+    }
+  };
+
+  if args.readme && args.year != DEFAULT_YEAR {
+    eprintln!(
+      "--readme only supports the benchmarks of {}, the year of this readme.",
+      DEFAULT_YEAR
+    );
+    process::exit(1);
+  }
+  // the table replaces the previous one, so it has to include every part.
+  if args.readme
+    && (args.days != DaySelection::all() || args.part.is_some() || args.skip_slow.is_some())
+  {
+    eprintln!("--readme writes the table of all days and can't be combined with --days, --part or --skip-slow.");
+    process::exit(1);
+  }
+
+  if args.jobs.is_some() && memory::ENABLED {
+    eprintln!("--parallel and --jobs can't be combined with the alloc-stats feature: its counters are shared by all threads.");
+    process::exit(1);
+  }
+
+  let mut failed = false;
+
+  let tasks = plan(&args);
+  let timer = Instant::now();
+  let outcomes: Box<dyn Iterator<Item = Option<Vec<SolveResult>>>> = match args.jobs {
+    Some(jobs) => Box::new(solve_days_parallel(&tasks, &args, jobs).into_iter()),
+    None => {
+      // a part that timed out keeps allocating on its thread, so the heap usage of the parts after
+      // it is unknown.
+      let (args, mut abandoned) = (&args, false);
+      Box::new(tasks.iter().map(move |task| {
+        let mut results = solve_day(task, args);
+        for result in results.iter_mut().flatten() {
+          if abandoned {
+            result.memory = None;
+          }
+          abandoned |= result.timed_out;
+        }
+        results
+      }))
+    }
+  };
+
+  let results: Vec<SolveResult> = tasks
+    .iter()
+    .zip(outcomes)
+    .filter(|(_, results)| results.is_some() || !args.only_solved)
+    .flat_map(|(task, results)| {
+      let verdicts = match (&results, args.check) {
+        (Some(results), true) => results
+          .iter()
+          .map(|r| Verdict::check(args.year, r))
+          .collect(),
+        _ => vec![],
+      };
+      failed |= verdicts.iter().any(Verdict::is_incorrect);
+      failed |= results.iter().flatten().any(|r| r.error.is_some());
+
+      if args.format == Format::Text {
+        println!("----------");
+        println!("{}| Day {:02} |{}", ANSI_BOLD, task.day, ANSI_RESET);
+        println!("----------");
+
+        match &results {
+          Some(results) => {
+            for part in 1..=2 {
+              if let Some(i) = results.iter().position(|r| r.part == part) {
+                print!("{}", results[i]);
+                if let Some(verdict) = verdicts.get(i) {
+                  println!("{}", verdict);
+                }
+              } else if let Some((_, last)) = task.skipped.iter().find(|(p, _)| *p == part) {
+                println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+                println!("skipped (last run: {:.2?}).", last);
+              }
+            }
+          }
+          None => println!("Not solved."),
+        }
       } else {
-        advent_of_code::parse_exec_time(&output)
+        verdicts
+          .iter()
+          .zip(results.iter().flatten())
+          .filter(|(verdict, _)| verdict.is_incorrect())
+          .for_each(|(verdict, result)| {
+            eprintln!("Day {:02}, part {}: {}", result.day, result.part, verdict)
+          });
       }
+
+      results.unwrap_or_else(|| {
+        task
+          .parts
+          .iter()
+          .map(|part| SolveResult::unsolved(task.day, *part))
+          .collect()
+      })
     })
-    .sum();
+    .collect();
+  let wall_clock = timer.elapsed();
+
+  // timings of concurrent runs are skewed by contention and would pollute the history.
+  if args.jobs.is_none() && args.year == DEFAULT_YEAR {
+    if let Err(e) = timings::save(&results) {
+      eprintln!("Failed to record timings: {}", e);
+    }
+  }
+
+  match args.format {
+    Format::Text => {
+      let total: Duration = results
+        .iter()
+        .filter(|r| !r.timed_out)
+        .map(|r| r.duration + r.parse.unwrap_or_default())
+        .sum();
+
+      println!(
+        "{}Total:{} {}{:.2}ms{}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total.as_secs_f64() * 1000_f64,
+        ANSI_RESET
+      );
+      if let Some(jobs) = args.jobs {
+        println!(
+          "{}Wall clock (jobs: {}):{} {}{:.2}ms{}",
+          ANSI_BOLD,
+          jobs,
+          ANSI_RESET,
+          ANSI_ITALIC,
+          wall_clock.as_secs_f64() * 1000_f64,
+          ANSI_RESET
+        );
+      }
+    }
+    Format::Json => println!("{}", report::to_json(&results)),
+    Format::Csv => print!("{}", report::to_csv(&results)),
+  }
+
+  if args.readme {
+    if let Err(e) = write_readme(&results) {
+      eprintln!("Failed to update README.md: {}", e);
+      process::exit(1);
+    }
+  }
+
+  if failed {
+    process::exit(1);
+  }
+}
+
+fn write_readme(results: &[SolveResult]) -> Result<(), String> {
+  if cfg!(debug_assertions) {
+    eprintln!(
+      "Warning: writing timings of a debug build. Use `cargo time` to run in release mode."
+    );
+  }
+
+  let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+  let readme = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+  let updated = report::update_readme(&readme, &report::to_markdown(results)).ok_or_else(|| {
+    format!(
+      "could not find two \"{}\" lines to place the table between.",
+      report::README_MARKER
+    )
+  })?;
+  fs::write(&path, updated).map_err(|e| e.to_string())?;
 
-  println!(
-    "{}Total:{} {}{:.2}ms{}",
-    ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-  );
+  // stdout may be json or csv output.
+  eprintln!("🎄 Successfully wrote benchmarks to \"README.md\".");
+  Ok(())
 }
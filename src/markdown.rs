@@ -0,0 +1,507 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Renders the puzzle descriptions in `src/puzzles/` for the terminal, used by `cargo read`.
+ * Supports the markdown that puzzles are written in: headings, paragraphs, emphasis, code, lists
+ * and links.
+ */
+use crate::aoc_client::DEFAULT_BASE_URL;
+use crate::{ANSI_BOLD, ANSI_CYAN, ANSI_RESET, ANSI_UNDERLINE};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Style {
+  emphasis: bool,
+  strong: bool,
+  code: bool,
+  link: bool,
+}
+
+impl Style {
+  // emphasis is shown in bold, like the highlighted words on the website.
+  fn codes(self) -> String {
+    let mut codes = String::new();
+    if self.emphasis || self.strong {
+      codes.push_str(ANSI_BOLD);
+    }
+    if self.code {
+      codes.push_str(ANSI_CYAN);
+    }
+    if self.link {
+      codes.push_str(ANSI_UNDERLINE);
+    }
+    codes
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Block {
+  Heading(String),
+  Paragraph(String),
+  Item {
+    depth: usize,
+    marker: String,
+    text: String,
+  },
+  Code(Vec<String>),
+}
+
+/// The footnotes of the links in a page.
+struct Links<'a> {
+  /// The address of the page, to resolve relative links.
+  page: &'a str,
+  urls: Vec<String>,
+}
+
+/// A word including the escape codes of its styles, and the number of columns it takes up.
+struct Word {
+  text: String,
+  width: usize,
+}
+
+/// Renders `markdown` with escape codes, wrapping text at `width` columns. Code blocks are not
+/// wrapped, since their lines are often grids. Links are numbered and listed at the end, relative
+/// ones resolved against the address of the `page`.
+pub fn render(markdown: &str, width: usize, page: &str) -> String {
+  let mut links = Links { page, urls: vec![] };
+  let mut lines: Vec<String> = vec![];
+  let mut previous_item = false;
+
+  for block in blocks(markdown) {
+    let item = matches!(block, Block::Item { .. });
+    if !lines.is_empty() && (!item || !previous_item) {
+      lines.push(String::new());
+    }
+    previous_item = item;
+
+    match block {
+      Block::Heading(text) => {
+        let strong = Style {
+          strong: true,
+          ..Style::default()
+        };
+        let words = words(&inline(&text, strong, &mut links));
+        lines.extend(wrap(&words, width, "", ""));
+      }
+      Block::Paragraph(text) => {
+        let words = words(&inline(&text, Style::default(), &mut links));
+        lines.extend(wrap(&words, width, "", ""));
+      }
+      Block::Item {
+        depth,
+        marker,
+        text,
+      } => {
+        let first = format!("{}{} ", "  ".repeat(depth), marker);
+        let rest = " ".repeat(first.chars().count());
+        let words = words(&inline(&text, Style::default(), &mut links));
+        lines.extend(wrap(&words, width, &first, &rest));
+      }
+      Block::Code(code) => lines.extend(code.iter().map(|line| match line.is_empty() {
+        true => String::new(),
+        false => format!("    {}{}{}", ANSI_CYAN, line, ANSI_RESET),
+      })),
+    }
+  }
+
+  if !links.urls.is_empty() {
+    lines.push(String::new());
+    lines.extend(
+      links
+        .urls
+        .iter()
+        .enumerate()
+        .map(|(i, url)| format!("[{}]: {}", i + 1, url)),
+    );
+  }
+
+  lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+fn blocks(markdown: &str) -> Vec<Block> {
+  let lines = markdown.lines().collect::<Vec<_>>();
+  let mut blocks = vec![];
+  let mut i = 0;
+
+  while i < lines.len() {
+    let line = lines[i];
+    if line.trim().is_empty() {
+      i += 1;
+      continue;
+    }
+
+    if is_fence(line) {
+      let end = (i + 1..lines.len())
+        .find(|&j| is_fence(lines[j]))
+        .unwrap_or(lines.len());
+      let mut code = lines[i + 1..end].to_vec();
+      while code.last().is_some_and(|line| line.trim().is_empty()) {
+        code.pop();
+      }
+      blocks.push(Block::Code(
+        code.iter().map(|line| line.to_string()).collect(),
+      ));
+      i = end + 1;
+      continue;
+    }
+
+    if let Some(text) = heading(line) {
+      blocks.push(Block::Heading(text.to_string()));
+      i += 1;
+      continue;
+    }
+
+    // paragraphs and list items continue until the next block, lines are joined with a space.
+    let item = list_item(line);
+    let mut text = item
+      .as_ref()
+      .map_or(line, |(_, _, text)| text)
+      .trim()
+      .to_string();
+    let mut underlined = false;
+    i += 1;
+    while i < lines.len() && !starts_block(lines[i]) {
+      if item.is_none() && is_underline(lines[i]) {
+        underlined = true;
+        i += 1;
+        break;
+      }
+      text.push(' ');
+      text.push_str(lines[i].trim());
+      i += 1;
+    }
+
+    blocks.push(match item {
+      Some((depth, marker, _)) => Block::Item {
+        depth,
+        marker,
+        text,
+      },
+      None if underlined => Block::Heading(text),
+      None => Block::Paragraph(text),
+    });
+  }
+
+  blocks
+}
+
+fn starts_block(line: &str) -> bool {
+  line.trim().is_empty() || is_fence(line) || heading(line).is_some() || list_item(line).is_some()
+}
+
+fn is_fence(line: &str) -> bool {
+  line.trim_start().starts_with("```")
+}
+
+/// The text of an `## atx` heading.
+fn heading(line: &str) -> Option<&str> {
+  let text = line.trim_start_matches('#');
+  (text.len() < line.len() && text.starts_with(' '))
+    .then(|| text.trim().trim_end_matches('#').trim())
+}
+
+/// The line below a `setext` heading.
+fn is_underline(line: &str) -> bool {
+  let line = line.trim();
+  !line.is_empty() && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+/// The nesting depth, the marker to show and the text of a list item.
+fn list_item(line: &str) -> Option<(usize, String, &str)> {
+  let trimmed = line.trim_start();
+  let depth = (line.len() - trimmed.len()) / 2;
+  if let Some(text) = ["* ", "- ", "+ "]
+    .iter()
+    .find_map(|bullet| trimmed.strip_prefix(bullet))
+  {
+    return Some((depth, "•".to_string(), text));
+  }
+
+  let (number, text) = trimmed.split_once(". ")?;
+  (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+    .then(|| (depth, format!("{}.", number), text))
+}
+
+/// The characters of `text` with their styles. Links are replaced by their text and the number of
+/// their footnote in `links`.
+fn inline(text: &str, base: Style, links: &mut Links) -> Vec<(char, Style)> {
+  let chars = text.chars().collect::<Vec<_>>();
+  let mut out = vec![];
+  let mut style = base;
+  let mut i = 0;
+
+  while i < chars.len() {
+    match chars[i] {
+      '\\' if i + 1 < chars.len() => {
+        out.push((chars[i + 1], style));
+        i += 2;
+      }
+      '`' => match find(&chars, i + 1, "`") {
+        Some(end) => {
+          let mut code = &chars[i + 1..end];
+          let mut code_style = Style {
+            code: true,
+            ..style
+          };
+          // highlighted code on the website ends up as emphasis inside of the code span.
+          if code.len() > 2 && code[0] == '*' && code[code.len() - 1] == '*' {
+            code = &code[1..code.len() - 1];
+            code_style.emphasis = true;
+          }
+          out.extend(code.iter().map(|&c| (c, code_style)));
+          i = end + 1;
+        }
+        None => {
+          out.push(('`', style));
+          i += 1;
+        }
+      },
+      '*' => {
+        let strong = chars.get(i + 1) == Some(&'*');
+        let marker = if strong { "**" } else { "*" };
+        let active = if strong { style.strong } else { style.emphasis };
+        // only opens if it is followed by a word and closed later, e.g. not in `2 * 3`.
+        let opens = chars
+          .get(i + marker.len())
+          .is_some_and(|c| !c.is_whitespace())
+          && find(&chars, i + marker.len(), marker).is_some();
+
+        if active || opens {
+          match strong {
+            true => style.strong = !style.strong,
+            false => style.emphasis = !style.emphasis,
+          }
+          i += marker.len();
+        } else {
+          out.push(('*', style));
+          i += 1;
+        }
+      }
+      '[' => {
+        // brackets and parentheses nest, e.g. in `[[a](b) [c](d(e))]`.
+        let link = matching(&chars, i, '[', ']')
+          .filter(|&close| chars.get(close + 1) == Some(&'('))
+          .and_then(|close| Some((close, matching(&chars, close + 1, '(', ')')?)));
+        match link {
+          Some((close, end)) => {
+            let label = chars[i + 1..close].iter().collect::<String>();
+            let url = chars[close + 2..end].iter().collect::<String>();
+            out.extend(inline(
+              &label,
+              Style {
+                link: true,
+                ..style
+              },
+              links,
+            ));
+            let footnote = format!("[{}]", links.number(&url));
+            out.extend(footnote.chars().map(|c| (c, style)));
+            i = end + 1;
+          }
+          None => {
+            out.push(('[', style));
+            i += 1;
+          }
+        }
+      }
+      c => {
+        out.push((c, style));
+        i += 1;
+      }
+    }
+  }
+
+  out
+}
+
+fn find(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+  let pattern = pattern.chars().collect::<Vec<_>>();
+  (from..chars.len()).find(|&i| chars[i..].starts_with(&pattern))
+}
+
+/// The index of the `close` that matches the `open` at `start`.
+fn matching(chars: &[char], start: usize, open: char, close: char) -> Option<usize> {
+  let mut depth = 0;
+  for (i, &c) in chars.iter().enumerate().skip(start) {
+    if c == open {
+      depth += 1;
+    } else if c == close {
+      depth -= 1;
+      if depth == 0 {
+        return Some(i);
+      }
+    }
+  }
+  None
+}
+
+impl Links<'_> {
+  /// The number of the footnote of `url`, links to the same address share one.
+  fn number(&mut self, url: &str) -> usize {
+    let url = if url.starts_with('/') {
+      format!("{}{}", DEFAULT_BASE_URL, url)
+    } else if url.contains(':') {
+      url.to_string()
+    } else {
+      // relative to the page, e.g. `1/input` on `/2022/day/1`.
+      let dir = self.page.rfind('/').map_or(self.page, |i| &self.page[..=i]);
+      format!("{}{}", dir, url)
+    };
+
+    match self.urls.iter().position(|link| *link == url) {
+      Some(i) => i + 1,
+      None => {
+        self.urls.push(url);
+        self.urls.len()
+      }
+    }
+  }
+}
+
+fn words(chars: &[(char, Style)]) -> Vec<Word> {
+  chars
+    .split(|(c, _)| c.is_whitespace())
+    .filter(|word| !word.is_empty())
+    .map(|word| {
+      let mut text = String::new();
+      let mut active = Style::default();
+      for &(c, style) in word {
+        if style != active {
+          if active != Style::default() {
+            text.push_str(ANSI_RESET);
+          }
+          text.push_str(&style.codes());
+          active = style;
+        }
+        text.push(c);
+      }
+      if active != Style::default() {
+        text.push_str(ANSI_RESET);
+      }
+      Word {
+        text,
+        width: word.len(),
+      }
+    })
+    .collect()
+}
+
+/// Fills lines of up to `width` columns with `words`. The first line starts with `first`, the
+/// others with `rest`. Words that are too long for a line get a line of their own.
+fn wrap(words: &[Word], width: usize, first: &str, rest: &str) -> Vec<String> {
+  let mut lines = vec![];
+  let mut line = first.to_string();
+  let mut line_width = first.chars().count();
+  let mut empty = true;
+
+  for word in words {
+    if !empty && line_width + 1 + word.width > width {
+      lines.push(line);
+      line = rest.to_string();
+      line_width = rest.chars().count();
+      empty = true;
+    }
+    if !empty {
+      line.push(' ');
+      line_width += 1;
+    }
+    line.push_str(&word.text);
+    line_width += word.width;
+    empty = false;
+  }
+
+  lines.push(line);
+  lines
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Renders without escape codes, to compare the layout.
+  fn plain(markdown: &str, width: usize) -> String {
+    let mut rendered = render(markdown, width, "https://adventofcode.com/2022/day/1");
+    for code in [ANSI_BOLD, ANSI_CYAN, ANSI_UNDERLINE, ANSI_RESET] {
+      rendered = rendered.replace(code, "");
+    }
+    rendered
+  }
+
+  #[test]
+  fn test_blocks() {
+    let markdown = "\\--- Day 1: Test ---\n----------\n\nSome\ntext.\n\n* one\n  continued\n  * nested\n2. two\n\n```\n1 2\n\n3 4\n\n```\n## --- Part Two ---";
+    assert_eq!(
+      blocks(markdown),
+      vec![
+        Block::Heading("\\--- Day 1: Test ---".into()),
+        Block::Paragraph("Some text.".into()),
+        Block::Item {
+          depth: 0,
+          marker: "•".into(),
+          text: "one continued".into()
+        },
+        Block::Item {
+          depth: 1,
+          marker: "•".into(),
+          text: "nested".into()
+        },
+        Block::Item {
+          depth: 0,
+          marker: "2.".into(),
+          text: "two".into()
+        },
+        Block::Code(vec!["1 2".into(), "".into(), "3 4".into()]),
+        Block::Heading("--- Part Two ---".into()),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_inline() {
+    let mut links = Links {
+      page: "https://adventofcode.com/2022/day/1",
+      urls: vec![],
+    };
+    let rendered = words(&inline(
+      "a *b* `*1*`, 2 * 3 and [c d](/2022/day/1) [[e](1/input) [f](javascript:void(0);)]",
+      Style::default(),
+      &mut links,
+    ))
+    .into_iter()
+    .map(|word| word.text)
+    .collect::<Vec<_>>();
+
+    assert_eq!(
+      rendered,
+      vec![
+        "a".to_string(),
+        format!("{}b{}", ANSI_BOLD, ANSI_RESET),
+        format!("{}{}1{},", ANSI_BOLD, ANSI_CYAN, ANSI_RESET),
+        "2".into(),
+        "*".into(),
+        "3".into(),
+        "and".into(),
+        format!("{}c{}", ANSI_UNDERLINE, ANSI_RESET),
+        format!("{}d{}[1]", ANSI_UNDERLINE, ANSI_RESET),
+        format!("[{}e{}[2]", ANSI_UNDERLINE, ANSI_RESET),
+        format!("{}f{}[3]]", ANSI_UNDERLINE, ANSI_RESET),
+      ]
+    );
+    assert_eq!(
+      links.urls,
+      vec![
+        "https://adventofcode.com/2022/day/1",
+        "https://adventofcode.com/2022/day/1/input",
+        "javascript:void(0);"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_render() {
+    let markdown = "## --- Day 1 ---\n\nThe [elves](/2022/about) carry *many* snacks, see [here](/2022/about).\n\n* one item that is too long\n* two\n\n```\nwide grid line\n```";
+    assert_eq!(
+      plain(markdown, 16),
+      "--- Day 1 ---\n\nThe elves[1]\ncarry many\nsnacks, see\nhere[1].\n\n• one item that\n  is too long\n• two\n\n    wide grid line\n\n[1]: https://adventofcode.com/2022/about\n"
+    );
+  }
+}
@@ -0,0 +1,168 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Heap usage is only tracked when the `alloc-stats` feature is enabled, which replaces the
+ * global allocator with one that counts allocations.
+ */
+use std::fmt::Display;
+
+/// Whether heap usage is tracked, i.e. the crate is built with the `alloc-stats` feature.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Heap usage of a single run of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+  /// Highest number of bytes allocated at once, on top of what was allocated before the run.
+  pub peak_bytes: usize,
+  /// Number of allocations, including reallocations.
+  pub allocations: usize,
+}
+
+impl Display for MemoryStats {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = self.peak_bytes as f64;
+    let mut unit = 0;
+    while size >= 1024_f64 && unit < UNITS.len() - 1 {
+      size /= 1024_f64;
+      unit += 1;
+    }
+
+    if unit == 0 {
+      write!(f, "peak heap: {}B", self.peak_bytes)?;
+    } else {
+      write!(f, "peak heap: {:.2}{}", size, UNITS[unit])?;
+    }
+    write!(f, ", allocations: {}", self.allocations)
+  }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+  use std::alloc::{GlobalAlloc, Layout, System};
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  pub static CURRENT: AtomicUsize = AtomicUsize::new(0);
+  pub static PEAK: AtomicUsize = AtomicUsize::new(0);
+  pub static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+  struct CountingAllocator;
+
+  #[global_allocator]
+  static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+  fn grow(bytes: usize) {
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+  }
+
+  unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+      let ptr = System.alloc(layout);
+      if !ptr.is_null() {
+        grow(layout.size());
+      }
+      ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+      let ptr = System.alloc_zeroed(layout);
+      if !ptr.is_null() {
+        grow(layout.size());
+      }
+      ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+      System.dealloc(ptr, layout);
+      CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+      let new_ptr = System.realloc(ptr, layout, new_size);
+      if !new_ptr.is_null() {
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        grow(new_size);
+      }
+      new_ptr
+    }
+  }
+}
+
+/// Measures heap usage from `Tracker::start` until `Tracker::finish`.
+/// The counters are shared by all threads, so runs on other threads are included.
+pub struct Tracker {
+  #[cfg(feature = "alloc-stats")]
+  baseline: usize,
+  #[cfg(feature = "alloc-stats")]
+  count: usize,
+}
+
+#[cfg(feature = "alloc-stats")]
+impl Tracker {
+  pub fn start() -> Tracker {
+    use std::sync::atomic::Ordering;
+
+    let baseline = counting::CURRENT.load(Ordering::Relaxed);
+    counting::PEAK.store(baseline, Ordering::Relaxed);
+    Tracker {
+      baseline,
+      count: counting::COUNT.load(Ordering::Relaxed),
+    }
+  }
+
+  pub fn finish(self) -> Option<MemoryStats> {
+    use std::sync::atomic::Ordering;
+
+    Some(MemoryStats {
+      peak_bytes: counting::PEAK
+        .load(Ordering::Relaxed)
+        .saturating_sub(self.baseline),
+      allocations: counting::COUNT.load(Ordering::Relaxed) - self.count,
+    })
+  }
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+impl Tracker {
+  pub fn start() -> Tracker {
+    Tracker {}
+  }
+
+  pub fn finish(self) -> Option<MemoryStats> {
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_display() {
+    let stats = MemoryStats {
+      peak_bytes: 512,
+      allocations: 3,
+    };
+    assert_eq!(stats.to_string(), "peak heap: 512B, allocations: 3");
+
+    let stats = MemoryStats {
+      peak_bytes: 3 * 1024 * 1024 / 2,
+      allocations: 1,
+    };
+    assert_eq!(stats.to_string(), "peak heap: 1.50MiB, allocations: 1");
+  }
+
+  #[cfg(feature = "alloc-stats")]
+  #[test]
+  fn test_tracker() {
+    let tracker = Tracker::start();
+    let v = vec![0_u8; 4096];
+    drop(v);
+    let stats = tracker.finish().unwrap();
+    assert!(stats.peak_bytes >= 4096);
+    assert!(stats.allocations >= 1);
+  }
+}
@@ -0,0 +1,314 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::SolveResult;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Delimits the region of `README.md` that is rewritten with the benchmark table.
+pub const README_MARKER: &str = "<!--- benchmarking table --->";
+
+/// Output format of the all-days runner, selected with `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+  Text,
+  Json,
+  Csv,
+}
+
+impl FromStr for Format {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "text" => Ok(Format::Text),
+      "json" => Ok(Format::Json),
+      "csv" => Ok(Format::Csv),
+      _ => Err(format!(
+        "unknown format \"{}\", expected one of: text, json, csv",
+        s
+      )),
+    }
+  }
+}
+
+impl Display for Format {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Format::Text => write!(f, "text"),
+      Format::Json => write!(f, "json"),
+      Format::Csv => write!(f, "csv"),
+    }
+  }
+}
+
+/// Renders `results` as a JSON array with one object per day and part.
+/// Durations are reported in whole nanoseconds; unsolved parts have a `null` answer.
+/// For parts that timed out, the duration is the time limit.
+/// Heap usage is `null` unless built with the `alloc-stats` feature, parse time is `null` unless the
+/// day times its parsing separately. Parts that failed have the message of their `error`.
+pub fn to_json(results: &[SolveResult]) -> String {
+  let rows = results
+    .iter()
+    .map(|r| {
+      format!(
+        "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"duration_ns\": {}, \"parse_ns\": {}, \"solved\": {}, \"timed_out\": {}, \"peak_heap_bytes\": {}, \"allocations\": {}, \"error\": {}}}",
+        r.day,
+        r.part,
+        r.answer
+          .as_deref()
+          .map_or_else(|| "null".to_string(), json_string),
+        r.duration.as_nanos(),
+        r.parse
+          .map_or_else(|| "null".to_string(), |p| p.as_nanos().to_string()),
+        r.answer.is_some(),
+        r.timed_out,
+        r.memory
+          .map_or_else(|| "null".to_string(), |m| m.peak_bytes.to_string()),
+        r.memory
+          .map_or_else(|| "null".to_string(), |m| m.allocations.to_string()),
+        r.error
+          .as_ref()
+          .map_or_else(|| "null".to_string(), |e| json_string(&e.to_string()))
+      )
+    })
+    .collect::<Vec<_>>();
+
+  if rows.is_empty() {
+    "[]".to_string()
+  } else {
+    format!("[\n{}\n]", rows.join(",\n"))
+  }
+}
+
+/// Renders `results` as CSV with a header row. Unsolved parts have an empty answer,
+/// parse time, heap usage and error are empty if they were not measured, like in `to_json`.
+pub fn to_csv(results: &[SolveResult]) -> String {
+  results.iter().fold(
+    "day,part,answer,duration_ns,parse_ns,solved,timed_out,peak_heap_bytes,allocations,error\n"
+      .to_string(),
+    |mut csv, r| {
+      let _ = writeln!(
+        csv,
+        "{},{},{},{},{},{},{},{},{},{}",
+        r.day,
+        r.part,
+        csv_field(r.answer.as_deref().unwrap_or_default()),
+        r.duration.as_nanos(),
+        r.parse
+          .map_or_else(String::new, |p| p.as_nanos().to_string()),
+        r.answer.is_some(),
+        r.timed_out,
+        r.memory
+          .map_or_else(String::new, |m| m.peak_bytes.to_string()),
+        r.memory
+          .map_or_else(String::new, |m| m.allocations.to_string()),
+        r.error
+          .as_ref()
+          .map_or_else(String::new, |e| csv_field(&e.to_string()))
+      );
+      csv
+    },
+  )
+}
+
+/// Renders the timings of solved parts as a markdown table with one row per day and a total.
+/// Like the total printed by `cargo all`, a part's timing includes the parse time reported with it.
+pub fn to_markdown(results: &[SolveResult]) -> String {
+  let mut days: BTreeMap<u8, [Option<Duration>; 2]> = BTreeMap::new();
+  for r in results.iter().filter(|r| r.answer.is_some()) {
+    days.entry(r.day).or_default()[usize::from(r.part - 1)] =
+      Some(r.duration + r.parse.unwrap_or_default());
+  }
+
+  let mut table = "| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n".to_string();
+  for (day, parts) in &days {
+    let _ = writeln!(
+      table,
+      "| [Day {}](./src/bin/{:02}.rs) | {} | {} |",
+      day,
+      day,
+      markdown_duration(parts[0]),
+      markdown_duration(parts[1])
+    );
+  }
+
+  let total: Duration = days.values().flatten().flatten().sum();
+  let _ = write!(
+    table,
+    "\n**Total: {:.2}ms**",
+    total.as_secs_f64() * 1000_f64
+  );
+  table
+}
+
+fn markdown_duration(duration: Option<Duration>) -> String {
+  match duration {
+    Some(duration) => format!("`{:.1?}`", duration),
+    None => "-".to_string(),
+  }
+}
+
+/// Replaces the region between the two `README_MARKER` lines of `readme` with a benchmark section.
+/// Returns `None` if `readme` does not contain the markers.
+pub fn update_readme(readme: &str, table: &str) -> Option<String> {
+  let start = readme.find(README_MARKER)? + README_MARKER.len();
+  let end = start + readme[start..].find(README_MARKER)?;
+
+  Some(format!(
+    "{}\n## Benchmarks\n\n{}\n{}",
+    &readme[..start],
+    table,
+    &readme[end..]
+  ))
+}
+
+fn json_string(s: &str) -> String {
+  let mut out = String::with_capacity(s.len() + 2);
+  out.push('"');
+  for c in s.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      '\n' => out.push_str("\\n"),
+      '\r' => out.push_str("\\r"),
+      '\t' => out.push_str("\\t"),
+      c if (c as u32) < 0x20 => {
+        let _ = write!(out, "\\u{:04x}", c as u32);
+      }
+      c => out.push(c),
+    }
+  }
+  out.push('"');
+  out
+}
+
+fn csv_field(s: &str) -> String {
+  if s.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", s.replace('"', "\"\""))
+  } else {
+    s.to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::memory::MemoryStats;
+  use crate::AocError;
+  use std::time::Duration;
+
+  fn results() -> Vec<SolveResult> {
+    vec![
+      SolveResult {
+        day: 1,
+        part: 1,
+        answer: Some("24000".into()),
+        duration: Duration::from_nanos(1500),
+        stats: None,
+        timed_out: false,
+        memory: Some(MemoryStats {
+          peak_bytes: 2048,
+          allocations: 12,
+        }),
+        parse: Some(Duration::from_nanos(700)),
+        error: None,
+      },
+      SolveResult {
+        day: 10,
+        part: 2,
+        answer: Some("#\"a\",\n.".into()),
+        duration: Duration::from_nanos(20),
+        stats: None,
+        timed_out: false,
+        memory: None,
+        parse: None,
+        error: None,
+      },
+      SolveResult::unsolved(22, 1),
+      SolveResult::timed_out(22, 2, Duration::from_secs(1)),
+      SolveResult::failed(
+        23,
+        1,
+        AocError {
+          line: Some(17),
+          column: None,
+          message: "expected `move <n> from <n> to <n>`, found \"left\"".into(),
+        },
+      ),
+    ]
+  }
+
+  #[test]
+  fn test_to_json() {
+    assert_eq!(
+      to_json(&results()),
+      [
+        "[",
+        "  {\"day\": 1, \"part\": 1, \"answer\": \"24000\", \"duration_ns\": 1500, \"parse_ns\": 700, \"solved\": true, \"timed_out\": false, \"peak_heap_bytes\": 2048, \"allocations\": 12, \"error\": null},",
+        "  {\"day\": 10, \"part\": 2, \"answer\": \"#\\\"a\\\",\\n.\", \"duration_ns\": 20, \"parse_ns\": null, \"solved\": true, \"timed_out\": false, \"peak_heap_bytes\": null, \"allocations\": null, \"error\": null},",
+        "  {\"day\": 22, \"part\": 1, \"answer\": null, \"duration_ns\": 0, \"parse_ns\": null, \"solved\": false, \"timed_out\": false, \"peak_heap_bytes\": null, \"allocations\": null, \"error\": null},",
+        "  {\"day\": 22, \"part\": 2, \"answer\": null, \"duration_ns\": 1000000000, \"parse_ns\": null, \"solved\": false, \"timed_out\": true, \"peak_heap_bytes\": null, \"allocations\": null, \"error\": null},",
+        "  {\"day\": 23, \"part\": 1, \"answer\": null, \"duration_ns\": 0, \"parse_ns\": null, \"solved\": false, \"timed_out\": false, \"peak_heap_bytes\": null, \"allocations\": null, \"error\": \"parse error at line 17: expected `move <n> from <n> to <n>`, found \\\"left\\\"\"}",
+        "]",
+      ]
+      .join("\n")
+    );
+    assert_eq!(to_json(&[]), "[]");
+  }
+
+  #[test]
+  fn test_to_markdown() {
+    let mut results = results();
+    results.push(SolveResult {
+      day: 10,
+      part: 1,
+      answer: Some("13140".into()),
+      duration: Duration::from_micros(1000),
+      stats: None,
+      timed_out: false,
+      memory: None,
+      parse: Some(Duration::from_micros(250)),
+      error: None,
+    });
+
+    assert_eq!(
+      to_markdown(&results),
+      "| Day | Part 1 | Part 2 |\n\
+       | :---: | :---: | :---: |\n\
+       | [Day 1](./src/bin/01.rs) | `2.2µs` | - |\n\
+       | [Day 10](./src/bin/10.rs) | `1.2ms` | `20.0ns` |\n\
+       \n\
+       **Total: 1.25ms**"
+    );
+  }
+
+  #[test]
+  fn test_update_readme() {
+    let readme = format!("# AoC\n\n{0}\nold table\n{0}\n\n---\n", README_MARKER);
+    assert_eq!(
+      update_readme(&readme, "new table"),
+      Some(format!(
+        "# AoC\n\n{0}\n## Benchmarks\n\nnew table\n{0}\n\n---\n",
+        README_MARKER
+      ))
+    );
+    assert_eq!(update_readme("# AoC\n", "new table"), None);
+  }
+
+  #[test]
+  fn test_to_csv() {
+    assert_eq!(
+      to_csv(&results()),
+      "day,part,answer,duration_ns,parse_ns,solved,timed_out,peak_heap_bytes,allocations,error\n\
+       1,1,24000,1500,700,true,false,2048,12,\n\
+       10,2,\"#\"\"a\"\",\n.\",20,,true,false,,,\n\
+       22,1,,0,,false,false,,,\n\
+       22,2,,1000000000,,false,true,,,\n\
+       23,1,,0,,false,false,,,\"parse error at line 17: expected `move <n> from <n> to <n>`, found \"\"left\"\"\"\n"
+    );
+  }
+}
@@ -0,0 +1,75 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::str::FromStr;
+
+/// A set of days given as a comma-separated list of days and ranges, e.g. `5,7-12`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(pub Vec<u8>);
+
+impl DaySelection {
+  pub fn all() -> DaySelection {
+    DaySelection((1..=25).collect())
+  }
+}
+
+impl FromStr for DaySelection {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut days = vec![];
+
+    for item in s.split(',').map(str::trim) {
+      let (from, to) = match item.split_once('-') {
+        Some((from, to)) => (parse_day(from)?, parse_day(to)?),
+        None => (parse_day(item)?, parse_day(item)?),
+      };
+      if from > to {
+        return Err(format!("invalid range \"{}\"", item));
+      }
+      days.extend(from..=to);
+    }
+
+    days.sort_unstable();
+    days.dedup();
+    Ok(DaySelection(days))
+  }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+  match s.trim().parse() {
+    Ok(day) if (1..=25).contains(&day) => Ok(day),
+    _ => Err(format!(
+      "invalid day \"{}\", expected a number from 1 to 25",
+      s
+    )),
+  }
+}
+
+/// Parses the value of `--part`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+  match s {
+    "1" => Ok(1),
+    "2" => Ok(2),
+    _ => Err(format!("invalid part \"{}\", expected 1 or 2", s)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_day_selection() {
+    assert_eq!(
+      "5,7-12".parse(),
+      Ok(DaySelection(vec![5, 7, 8, 9, 10, 11, 12]))
+    );
+    assert_eq!("3, 1-2,2".parse(), Ok(DaySelection(vec![1, 2, 3])));
+    assert!("12-7".parse::<DaySelection>().is_err());
+    assert!("0".parse::<DaySelection>().is_err());
+    assert!("26".parse::<DaySelection>().is_err());
+    assert!("a-b".parse::<DaySelection>().is_err());
+  }
+}
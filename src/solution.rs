@@ -0,0 +1,89 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * An alternative to the free `part_one` and `part_two` functions of a day: the input is parsed once,
+ * shared by both parts and timed on its own.
+ */
+use crate::{Answer, AocError, Bench, SolveResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+use std::process;
+use std::time::{Duration, Instant};
+
+/// A day whose parts work on a parsed input. Use `advent_of_code::solution!(<type>)` to generate
+/// the day's `main` and free functions, and register it as `<day> => <module>::<type>` in `days.rs`.
+/// `Parsed` may borrow from the input, e.g. `type Parsed<'a> = Vec<&'a str>;`.
+/// Malformed input is reported by returning an `AocError` from `parse`. Like the free functions,
+/// parts return an `Answer`, e.g. `type PartOne = Option<u32>;` or `Result<u32, AocError>` for parts
+/// that can fail on input that parses.
+pub trait Solution {
+  const DAY: u8;
+  const YEAR: u16 = crate::DEFAULT_YEAR;
+
+  type Parsed<'a>;
+  type PartOne: Answer;
+  type PartTwo: Answer;
+
+  fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError>;
+  fn part_one(parsed: &Self::Parsed<'_>) -> Self::PartOne;
+  fn part_two(parsed: &Self::Parsed<'_>) -> Self::PartTwo;
+}
+
+/// Entry point of `cargo solve <day>` for a `Solution`.
+pub fn run<S: Solution>() {
+  let args = crate::SolveArgs::from_env();
+  let input = args.read_input(S::YEAR, S::DAY);
+
+  let timer = Instant::now();
+  let parsed = S::parse(&input);
+  let elapsed = timer.elapsed();
+  println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+  let parsed = match parsed {
+    Ok(parsed) => parsed,
+    Err(error) => {
+      println!("{}{}{}", ANSI_RED, error, ANSI_RESET);
+      process::exit(1);
+    }
+  };
+  println!("{}(elapsed: {:.2?}){}", ANSI_ITALIC, elapsed, ANSI_RESET);
+
+  let results = [
+    crate::solve!(S::DAY, 1, S::part_one, &parsed, args.bench),
+    crate::solve!(S::DAY, 2, S::part_two, &parsed, args.bench),
+  ];
+  args.report(S::YEAR, &results);
+}
+
+/// Entry point of the all-days runner into `S`. The input is parsed once for all `parts`, and the
+/// parse time is reported with the first of them so it is counted once.
+pub fn solve_parts<S: Solution>(
+  input: &str,
+  parts: &[u8],
+  bench: Option<Bench>,
+  report: &mut dyn FnMut(SolveResult),
+) {
+  let timer = Instant::now();
+  let parsed = S::parse(input);
+  let mut parse = Some(timer.elapsed());
+  let with_parse = |result: SolveResult, parse: Option<Duration>| match parse {
+    Some(parse) => result.with_parse(parse),
+    None => result,
+  };
+
+  let parsed = match parsed {
+    Ok(parsed) => parsed,
+    Err(error) => {
+      for &part in parts {
+        let result = SolveResult::failed(S::DAY, part, error.clone());
+        report(with_parse(result, parse.take()));
+      }
+      return;
+    }
+  };
+
+  for &part in parts {
+    let result = match part {
+      1 => crate::solve!(S::DAY, 1, S::part_one, &parsed, bench),
+      _ => crate::solve!(S::DAY, 2, S::part_two, &parsed, bench),
+    };
+    report(with_parse(result, parse.take()));
+  }
+}
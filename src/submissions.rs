@@ -0,0 +1,213 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Log of the answers submitted with `cargo submit`, kept in `src/answers/submissions.txt`.
+ */
+use crate::aoc_client::Outcome;
+use crate::year_dir;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// One submitted answer and the response to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+  /// Seconds since the unix epoch.
+  pub timestamp: u64,
+  pub day: u8,
+  pub part: u8,
+  pub answer: String,
+  pub outcome: Outcome,
+}
+
+impl Entry {
+  pub fn now(day: u8, part: u8, answer: &str, outcome: Outcome) -> Entry {
+    Entry {
+      timestamp: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs()),
+      day,
+      part,
+      answer: answer.to_string(),
+      outcome,
+    }
+  }
+}
+
+fn get_path(year: u16) -> PathBuf {
+  year_dir(year).join("answers").join("submissions.txt")
+}
+
+/// Loads all submissions of `year`, oldest first.
+pub fn load(year: u16) -> Vec<Entry> {
+  fs::read_to_string(get_path(year))
+    .map(|contents| parse(&contents))
+    .unwrap_or_default()
+}
+
+pub fn record(year: u16, entry: &Entry) -> std::io::Result<()> {
+  let path = get_path(year);
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)?;
+  }
+
+  OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(path)?
+    .write_all(serialize(entry).as_bytes())
+}
+
+/// Explains why `answer` is known to be wrong without submitting it, based on earlier submissions:
+/// it was submitted before, or an earlier answer that was too high or too low rules it out.
+pub fn known_wrong(history: &[Entry], day: u8, part: u8, answer: &str) -> Option<String> {
+  let submissions = history
+    .iter()
+    .filter(|e| e.day == day && e.part == part && e.outcome.is_verdict())
+    .collect::<Vec<_>>();
+
+  if let Some(previous) = submissions.iter().find(|e| e.answer == answer) {
+    return Some(format!(
+      "{} was already submitted on {}: {}",
+      answer,
+      crate::timings::format_date(previous.timestamp),
+      previous.outcome
+    ));
+  }
+
+  let number = answer.parse::<i128>().ok()?;
+  submissions.iter().find_map(|e| {
+    let previous = e.answer.parse::<i128>().ok()?;
+    match e.outcome {
+      Outcome::TooHigh if number >= previous => Some(format!(
+        "{} is not lower than {}, which was too high.",
+        answer, previous
+      )),
+      Outcome::TooLow if number <= previous => Some(format!(
+        "{} is not higher than {}, which was too low.",
+        answer, previous
+      )),
+      _ => None,
+    }
+  })
+}
+
+fn slug(outcome: &Outcome) -> &'static str {
+  match outcome {
+    Outcome::Correct => "correct",
+    Outcome::TooHigh => "too-high",
+    Outcome::TooLow => "too-low",
+    Outcome::Incorrect => "incorrect",
+    Outcome::RateLimited(_) => "rate-limited",
+    Outcome::AlreadySolved => "already-solved",
+    Outcome::Unknown(_) => "unknown",
+  }
+}
+
+fn from_slug(slug: &str) -> Option<Outcome> {
+  match slug {
+    "correct" => Some(Outcome::Correct),
+    "too-high" => Some(Outcome::TooHigh),
+    "too-low" => Some(Outcome::TooLow),
+    "incorrect" => Some(Outcome::Incorrect),
+    "rate-limited" => Some(Outcome::RateLimited(Duration::ZERO)),
+    "already-solved" => Some(Outcome::AlreadySolved),
+    "unknown" => Some(Outcome::Unknown(String::new())),
+    _ => None,
+  }
+}
+
+// the answer comes last, it may contain spaces.
+fn parse(contents: &str) -> Vec<Entry> {
+  contents
+    .lines()
+    .filter_map(|line| {
+      let fields = line.splitn(5, ' ').collect::<Vec<_>>();
+      match fields[..] {
+        [timestamp, day, part, outcome, answer] => Some(Entry {
+          timestamp: timestamp.parse().ok()?,
+          day: day.parse().ok()?,
+          part: part.parse().ok()?,
+          answer: answer.to_string(),
+          outcome: from_slug(outcome)?,
+        }),
+        _ => None,
+      }
+    })
+    .collect()
+}
+
+fn serialize(entry: &Entry) -> String {
+  format!(
+    "{} {} {} {} {}\n",
+    entry.timestamp,
+    entry.day,
+    entry.part,
+    slug(&entry.outcome),
+    entry.answer
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn entry(part: u8, answer: &str, outcome: Outcome) -> Entry {
+    Entry {
+      timestamp: 1670000000,
+      day: 1,
+      part,
+      answer: answer.to_string(),
+      outcome,
+    }
+  }
+
+  #[test]
+  fn test_parse_serialize() {
+    let entries = vec![
+      entry(1, "24000", Outcome::TooLow),
+      entry(2, "two words", Outcome::RateLimited(Duration::ZERO)),
+    ];
+    let contents = entries.iter().map(serialize).collect::<String>();
+    assert_eq!(
+      contents,
+      "1670000000 1 1 too-low 24000\n1670000000 1 2 rate-limited two words\n"
+    );
+    assert_eq!(parse(&contents), entries);
+    assert_eq!(parse("garbage\n"), vec![]);
+  }
+
+  #[test]
+  fn test_known_wrong() {
+    let history = vec![
+      entry(1, "100", Outcome::TooHigh),
+      entry(1, "10", Outcome::TooLow),
+      entry(1, "abc", Outcome::Incorrect),
+      entry(1, "50", Outcome::RateLimited(Duration::ZERO)),
+      entry(2, "7", Outcome::TooHigh),
+    ];
+
+    assert_eq!(
+      known_wrong(&history, 1, 1, "abc"),
+      Some("abc was already submitted on 2022-12-02: That's not the right answer.".into())
+    );
+    assert_eq!(
+      known_wrong(&history, 1, 1, "120"),
+      Some("120 is not lower than 100, which was too high.".into())
+    );
+    assert_eq!(
+      known_wrong(&history, 1, 1, "10"),
+      Some(
+        "10 was already submitted on 2022-12-02: That's not the right answer, it is too low."
+          .into()
+      )
+    );
+    assert_eq!(known_wrong(&history, 1, 1, "50"), None);
+    assert_eq!(
+      known_wrong(&history, 1, 2, "50"),
+      Some("50 is not lower than 7, which was too high.".into())
+    );
+    assert_eq!(known_wrong(&history, 2, 1, "50"), None);
+  }
+}
@@ -0,0 +1,318 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::SolveResult;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Most recent duration of each day and part, keyed by `(day, part)`.
+pub type Timings = BTreeMap<(u8, u8), Duration>;
+
+/// One part's timing from a single run of the all-days runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+  /// Output of `git describe --always --dirty` at the time of the run.
+  pub commit: String,
+  /// Seconds since the unix epoch.
+  pub timestamp: u64,
+  /// `debug` or `release`. Timings are only comparable within the same profile.
+  pub profile: String,
+  pub day: u8,
+  pub part: u8,
+  /// For parts that timed out, this is the time limit.
+  pub duration: Duration,
+  pub timed_out: bool,
+}
+
+/// The history is kept next to the sources so it survives `cargo clean`, no matter which directory
+/// the runner is started in. It is not committed.
+fn get_path() -> PathBuf {
+  Path::new(env!("CARGO_MANIFEST_DIR")).join("perf_history.txt")
+}
+
+pub fn current_profile() -> &'static str {
+  if cfg!(debug_assertions) {
+    "debug"
+  } else {
+    "release"
+  }
+}
+
+fn current_commit() -> String {
+  Command::new("git")
+    .args(["describe", "--always", "--dirty"])
+    .output()
+    .ok()
+    .filter(|output| output.status.success())
+    .and_then(|output| String::from_utf8(output.stdout).ok())
+    .map(|commit| commit.trim().to_string())
+    .filter(|commit| !commit.is_empty())
+    .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Loads all recorded entries, oldest first. Returns an empty list if nothing was recorded yet.
+pub fn load_history() -> Vec<Entry> {
+  fs::read_to_string(get_path())
+    .map(|contents| parse(&contents))
+    .unwrap_or_default()
+}
+
+/// Loads the most recent timing of every part that was run with the current build profile.
+pub fn load() -> Timings {
+  latest(&load_history(), current_profile())
+}
+
+fn latest(history: &[Entry], profile: &str) -> Timings {
+  history
+    .iter()
+    .filter(|entry| entry.profile == profile)
+    .map(|entry| ((entry.day, entry.part), entry.duration))
+    .collect()
+}
+
+/// Appends all solved or timed out parts in `results` to the history.
+pub fn save(results: &[SolveResult]) -> std::io::Result<()> {
+  let commit = current_commit();
+  let timestamp = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |d| d.as_secs());
+
+  let entries = results
+    .iter()
+    .filter(|r| r.answer.is_some() || r.timed_out)
+    .map(|r| Entry {
+      commit: commit.clone(),
+      timestamp,
+      profile: current_profile().to_string(),
+      day: r.day,
+      part: r.part,
+      // like the total of `cargo all`, includes the parse time reported with the part.
+      duration: r.duration + r.parse.unwrap_or_default(),
+      timed_out: r.timed_out,
+    })
+    .collect::<Vec<_>>();
+
+  if entries.is_empty() {
+    return Ok(());
+  }
+
+  OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(get_path())?
+    .write_all(serialize(&entries).as_bytes())
+}
+
+fn parse(contents: &str) -> Vec<Entry> {
+  contents
+    .lines()
+    .filter_map(|line| {
+      let fields = line.split_whitespace().collect::<Vec<_>>();
+      match fields[..] {
+        [commit, timestamp, profile, day, part, nanos, status] => Some(Entry {
+          commit: commit.to_string(),
+          timestamp: timestamp.parse().ok()?,
+          profile: profile.to_string(),
+          day: day.parse().ok()?,
+          part: part.parse().ok()?,
+          duration: Duration::from_nanos(nanos.parse().ok()?),
+          timed_out: status == "timeout",
+        }),
+        _ => None,
+      }
+    })
+    .collect()
+}
+
+fn serialize(entries: &[Entry]) -> String {
+  entries
+    .iter()
+    .map(|e| {
+      format!(
+        "{} {} {} {} {} {} {}\n",
+        e.commit,
+        e.timestamp,
+        e.profile,
+        e.day,
+        e.part,
+        e.duration.as_nanos(),
+        if e.timed_out { "timeout" } else { "ok" }
+      )
+    })
+    .collect()
+}
+
+/// How a part's timings developed over the recorded runs of one profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trend<'a> {
+  pub day: u8,
+  pub part: u8,
+  /// All finished runs of the part, oldest first. Never empty.
+  pub runs: Vec<&'a Entry>,
+  /// The fastest of `runs`.
+  pub best: &'a Entry,
+}
+
+impl Trend<'_> {
+  pub fn latest(&self) -> &Entry {
+    self.runs[self.runs.len() - 1]
+  }
+
+  /// How much slower the latest run is than the best one, in percent.
+  pub fn slowdown(&self) -> f64 {
+    let best = self.best.duration.as_secs_f64();
+    if best == 0_f64 {
+      return 0_f64;
+    }
+    (self.latest().duration.as_secs_f64() / best - 1_f64) * 100_f64
+  }
+}
+
+/// Groups the finished runs in `history` that used `profile` by day and part.
+pub fn trends<'a>(history: &'a [Entry], profile: &str) -> Vec<Trend<'a>> {
+  let mut runs: BTreeMap<(u8, u8), Vec<&Entry>> = BTreeMap::new();
+  for entry in history
+    .iter()
+    .filter(|e| e.profile == profile && !e.timed_out)
+  {
+    runs.entry((entry.day, entry.part)).or_default().push(entry);
+  }
+
+  runs
+    .into_iter()
+    .map(|((day, part), runs)| Trend {
+      day,
+      part,
+      best: runs.iter().min_by_key(|e| e.duration).unwrap(),
+      runs,
+    })
+    .collect()
+}
+
+/// Renders durations as a bar chart of unicode blocks, scaled from the smallest to the largest.
+pub fn sparkline(durations: &[Duration]) -> String {
+  const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+  let (Some(min), Some(max)) = (durations.iter().min(), durations.iter().max()) else {
+    return String::new();
+  };
+  let range = (*max - *min).as_secs_f64();
+
+  durations
+    .iter()
+    .map(|d| {
+      if range == 0_f64 {
+        BARS[0]
+      } else {
+        let level = (*d - *min).as_secs_f64() / range * (BARS.len() - 1) as f64;
+        BARS[level.round() as usize]
+      }
+    })
+    .collect()
+}
+
+/// Formats a unix timestamp as `YYYY-MM-DD` (UTC).
+pub fn format_date(timestamp: u64) -> String {
+  // civil-from-days, see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+  let z = (timestamp / 86400) as i64 + 719468;
+  let era = z.div_euclid(146097);
+  let doe = z.rem_euclid(146097);
+  let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+  let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+  let mp = (5 * doy + 2) / 153;
+  let day = doy - (153 * mp + 2) / 5 + 1;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 };
+  let year = yoe + era * 400 + i64::from(month <= 2);
+
+  format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn entry(commit: &str, day: u8, part: u8, millis: u64) -> Entry {
+    Entry {
+      commit: commit.to_string(),
+      timestamp: 1671667200,
+      profile: "release".to_string(),
+      day,
+      part,
+      duration: Duration::from_millis(millis),
+      timed_out: false,
+    }
+  }
+
+  #[test]
+  fn test_roundtrip() {
+    let entries = vec![
+      entry("4138d5c", 1, 1, 2),
+      Entry {
+        timed_out: true,
+        profile: "debug".to_string(),
+        ..entry("4138d5c-dirty", 19, 2, 3000)
+      },
+    ];
+    assert_eq!(
+      serialize(&entries),
+      "4138d5c 1671667200 release 1 1 2000000 ok\n\
+       4138d5c-dirty 1671667200 debug 19 2 3000000000 timeout\n"
+    );
+    assert_eq!(parse(&serialize(&entries)), entries);
+    assert_eq!(parse("garbage\n1 2\n"), vec![]);
+  }
+
+  #[test]
+  fn test_latest() {
+    let history = vec![
+      entry("a", 1, 1, 5),
+      entry("b", 1, 1, 3),
+      Entry {
+        profile: "debug".to_string(),
+        ..entry("c", 1, 1, 50)
+      },
+    ];
+    assert_eq!(
+      latest(&history, "release"),
+      Timings::from([((1, 1), Duration::from_millis(3))])
+    );
+  }
+
+  #[test]
+  fn test_trends() {
+    let history = vec![
+      entry("a", 1, 1, 10),
+      entry("a", 1, 2, 4),
+      entry("b", 1, 1, 8),
+      entry("c", 1, 1, 12),
+    ];
+    let trends = trends(&history, "release");
+
+    assert_eq!(trends.len(), 2);
+    assert_eq!((trends[0].day, trends[0].part), (1, 1));
+    assert_eq!(trends[0].best.commit, "b");
+    assert_eq!(trends[0].latest().commit, "c");
+    assert!((trends[0].slowdown() - 50_f64).abs() < 1e-9);
+    assert_eq!(trends[1].slowdown(), 0_f64);
+  }
+
+  #[test]
+  fn test_sparkline() {
+    let durations = [0, 7, 14, 3].map(Duration::from_millis);
+    assert_eq!(sparkline(&durations), "▁▅█▃");
+    assert_eq!(sparkline(&[Duration::from_millis(1); 3]), "▁▁▁");
+    assert_eq!(sparkline(&[]), "");
+  }
+
+  #[test]
+  fn test_format_date() {
+    assert_eq!(format_date(0), "1970-01-01");
+    assert_eq!(format_date(1671667200), "2022-12-22");
+    assert_eq!(format_date(1709164800), "2024-02-29");
+  }
+}
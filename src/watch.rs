@@ -0,0 +1,203 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Helpers of `cargo watch-solve <day>`, which re-runs a day whenever one of its files changes.
+ */
+use crate::{year_dir, ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, DEFAULT_YEAR};
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Answers of part one and two, `None` if a part is not solved.
+pub type Answers = [Option<String>; 2];
+
+/// Name of the binary of a day, e.g. `01` or `2021-01` for other years.
+pub fn bin_name(year: u16, day: u8) -> String {
+  match year {
+    DEFAULT_YEAR => format!("{:02}", day),
+    year => format!("{}-{:02}", year, day),
+  }
+}
+
+/// The solution, examples and input of a day, as created by `cargo scaffold`.
+/// Named examples like `src/examples/09-a.txt` are included.
+pub fn watched_files(year: u16, day: u8) -> Vec<PathBuf> {
+  let day_padded = format!("{:02}", day);
+  let examples_dir = year_dir(year).join("examples");
+
+  let mut files = vec![
+    year_dir(DEFAULT_YEAR)
+      .join("bin")
+      .join(format!("{}.rs", bin_name(year, day))),
+    examples_dir.join(format!("{}.txt", day_padded)),
+    year_dir(year)
+      .join("inputs")
+      .join(format!("{}.txt", day_padded)),
+  ];
+
+  let named_prefix = format!("{}-", day_padded);
+  if let Ok(entries) = fs::read_dir(&examples_dir) {
+    let mut named = entries
+      .flatten()
+      .map(|entry| entry.path())
+      .filter(|path| {
+        path.extension().is_some_and(|ext| ext == "txt")
+          && path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with(&named_prefix))
+      })
+      .collect::<Vec<_>>();
+    named.sort();
+    files.extend(named);
+  }
+
+  files
+}
+
+/// Modification times of `files`, `None` for files that don't exist (yet).
+/// Two snapshots differ if any file was saved, created or deleted in between.
+pub fn snapshot(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+  files
+    .iter()
+    .map(|file| fs::metadata(file).and_then(|meta| meta.modified()).ok())
+    .collect()
+}
+
+/// Reads the answers from the output of `cargo solve <day>`, see the `Display` impl of `SolveResult`.
+pub fn parse_answers(output: &str) -> Answers {
+  let mut answers: Answers = [None, None];
+  let mut lines = output.lines();
+
+  while let Some(line) = lines.next() {
+    let part = match line
+      .strip_prefix(&format!("🎄 {}Part ", ANSI_BOLD))
+      .and_then(|rest| rest.strip_suffix(&format!("{} 🎄", ANSI_RESET)))
+    {
+      Some("1") => 0,
+      Some("2") => 1,
+      _ => continue,
+    };
+
+    // answers can span several lines, e.g. ascii art. The details follow the last one.
+    let details = format!(" {}(", ANSI_ITALIC);
+    let mut answer: Vec<&str> = vec![];
+    for line in lines.by_ref() {
+      if answer.is_empty() && (line == "not solved." || line.starts_with("timed out after")) {
+        break;
+      }
+      match line.rfind(&details) {
+        Some(end) => {
+          answer.push(&line[..end]);
+          answers[part] = Some(answer.join("\n"));
+          break;
+        }
+        None => answer.push(line),
+      }
+    }
+  }
+
+  answers
+}
+
+/// Describes how the answers changed since the previous run, one line per part.
+pub fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+  previous
+    .iter()
+    .zip(current)
+    .enumerate()
+    .map(|(i, (previous, current))| {
+      let part = format!("Part {}:", i + 1);
+      match (previous, current) {
+        (previous, current) if previous == current => format!(
+          "{} {}unchanged{} ({})",
+          part,
+          ANSI_ITALIC,
+          ANSI_RESET,
+          show(current)
+        ),
+        (_, None) => format!(
+          "{} {}{} → not solved{}",
+          part,
+          ANSI_RED,
+          show(previous),
+          ANSI_RESET
+        ),
+        (previous, current) => format!(
+          "{} {}{} → {}{}",
+          part,
+          ANSI_GREEN,
+          show(previous),
+          show(current),
+          ANSI_RESET
+        ),
+      }
+    })
+    .collect()
+}
+
+fn show(answer: &Option<String>) -> String {
+  match answer {
+    Some(answer) if answer.contains('\n') => format!("\n{}\n", answer),
+    Some(answer) => answer.to_string(),
+    None => "not solved".to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::SolveResult;
+  use std::time::Duration;
+
+  fn answers(one: Option<&str>, two: Option<&str>) -> Answers {
+    [one.map(str::to_string), two.map(str::to_string)]
+  }
+
+  #[test]
+  fn test_bin_name() {
+    assert_eq!(bin_name(DEFAULT_YEAR, 1), "01");
+    assert_eq!(bin_name(2021, 12), "2021-12");
+  }
+
+  #[test]
+  fn test_parse_answers() {
+    let solved = SolveResult::measure(1, 1, |_: &str| Some(24000), "");
+    let output = format!(
+      "🎄 {}Parse{} 🎄\n{}(elapsed: 1.00µs){}\n{}{}",
+      ANSI_BOLD,
+      ANSI_RESET,
+      ANSI_ITALIC,
+      ANSI_RESET,
+      solved,
+      SolveResult::unsolved(1, 2)
+    );
+    assert_eq!(parse_answers(&output), answers(Some("24000"), None));
+
+    let crt = SolveResult::measure(10, 2, |_: &str| Some("#..\n.#.\n..#"), "");
+    let output = format!("{}{}", SolveResult::timed_out(10, 1, Duration::ZERO), crt);
+    assert_eq!(parse_answers(&output), answers(None, Some("#..\n.#.\n..#")));
+
+    assert_eq!(
+      parse_answers("error: could not compile"),
+      answers(None, None)
+    );
+  }
+
+  #[test]
+  fn test_diff() {
+    let previous = answers(Some("24000"), Some("1"));
+    let current = answers(Some("24000"), Some("45000"));
+    assert_eq!(
+      diff(&previous, &current),
+      vec![
+        format!("Part 1: {}unchanged{} (24000)", ANSI_ITALIC, ANSI_RESET),
+        format!("Part 2: {}1 → 45000{}", ANSI_GREEN, ANSI_RESET),
+      ]
+    );
+
+    assert_eq!(
+      diff(&current, &answers(None, Some("45000")))[0],
+      format!("Part 1: {}24000 → not solved{}", ANSI_RED, ANSI_RESET)
+    );
+  }
+}